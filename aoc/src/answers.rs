@@ -0,0 +1,251 @@
+use std::{collections::BTreeMap, fmt::Display, path::{Path, PathBuf}};
+
+use anyhow::Context;
+use aoc_common::config;
+use serde::Deserialize;
+use xmas::{solution::{Part, PARTS}, solver::Params};
+
+use crate::days;
+
+/// A pair of input and recorded answers files found in each day's folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerSet {
+    /// The personal puzzle input, `input.txt` with `answers.toml`.
+    Input,
+    /// The public example of the puzzle, `example.txt` with `example_answers.toml`.
+    Example,
+}
+
+pub const ANSWER_SETS: [AnswerSet; 2] = [AnswerSet::Example, AnswerSet::Input];
+
+impl AnswerSet {
+    pub fn input_file(&self) -> &'static str {
+        match self {
+            AnswerSet::Input => "input.txt",
+            AnswerSet::Example => "example.txt",
+        }
+    }
+
+    pub fn answers_file(&self) -> &'static str {
+        match self {
+            AnswerSet::Input => "answers.toml",
+            AnswerSet::Example => "example_answers.toml",
+        }
+    }
+}
+
+impl Display for AnswerSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerSet::Input => write!(f, "input"),
+            AnswerSet::Example => write!(f, "example"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Expected answers of a day, a part is left out while it's unknown or unsolved. A `[params]`
+/// table holds the numbers the day is run with for this set, see [`xmas::solver::Solver::parse_with`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+    #[serde(default)]
+    params: BTreeMap<String, i64>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(s)?)
+    }
+
+    /// Reads an answers file, or returns `None` if there's none.
+    pub fn load(path: &Path) -> anyhow::Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|s| Answers::parse(&s))
+            .map(Some)
+            .with_context(|| format!("Error reading answers file {}.", path.display()))
+    }
+
+    pub fn params(&self) -> Params {
+        self.params.iter().map(|(name, &value)| (name.clone(), value)).collect()
+    }
+
+    pub fn get(&self, part: Part) -> Option<String> {
+        let answer = match part {
+            Part::One => &self.part_1,
+            Part::Two => &self.part_2,
+        };
+        answer.as_ref().map(|answer| answer.to_string())
+    }
+}
+
+/// Sets the answer of a part in an answers file, creating it if needed and keeping the rest as is.
+pub fn record_answer(path: &Path, part: Part, answer: &str) -> anyhow::Result<()> {
+    let content = match path.exists() {
+        true => std::fs::read_to_string(path)
+            .with_context(|| format!("Error reading answers file {}.", path.display()))?,
+        false => String::new(),
+    };
+
+    let mut document: toml_edit::DocumentMut = content.parse()?;
+    let key = format!("part_{}", part.number());
+    document[&key] = match answer.parse::<i64>() {
+        Ok(number) => toml_edit::value(number),
+        Err(_) => toml_edit::value(answer),
+    };
+    std::fs::write(path, document.to_string())
+        .with_context(|| format!("Error writing answers file {}.", path.display()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed { expected: String, actual: String },
+    Errored(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: usize,
+    pub set: AnswerSet,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {:02} part {} ({}): ", self.day, self.part, self.set)?;
+        match &self.outcome {
+            Outcome::Passed => write!(f, "ok"),
+            Outcome::Failed { expected, actual } => {
+                write!(f, "expected {expected:?}, got {actual:?}")
+            },
+            Outcome::Errored(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Passed
+    }
+}
+
+/// Paths of a day's input and answers files for a set, relative to the workspace `root`.
+pub fn set_paths(root: &Path, day: usize, set: AnswerSet) -> anyhow::Result<(PathBuf, PathBuf)> {
+    let folder = config::crate_dir(root, None, day)?;
+    Ok((folder.join(set.input_file()), folder.join(set.answers_file())))
+}
+
+/// Parameters a day runs with for a set, from its answers file.
+pub fn set_params(root: &Path, day: usize, set: AnswerSet) -> anyhow::Result<Params> {
+    let (_, answers_path) = set_paths(root, day, set)?;
+    Ok(Answers::load(&answers_path)?.unwrap_or_default().params())
+}
+
+/// Runs the recorded parts of a day for a set, nothing is checked if there's no answers file.
+pub fn check_day(root: &Path, day: usize, set: AnswerSet) -> anyhow::Result<Vec<Check>> {
+    let solution = days::get(day).with_context(|| format!("No solution found for day {day}"))?;
+    let (input_path, answers_path) = set_paths(root, day, set)?;
+    let Some(answers) = Answers::load(&answers_path)? else {
+        return Ok(Vec::new());
+    };
+    let input = std::fs::read_to_string(&input_path)
+        .with_context(|| format!("Error reading input file {}.", input_path.display()))?;
+
+    let (parts, expected): (Vec<_>, Vec<_>) = PARTS.iter()
+        .filter_map(|&part| answers.get(part).map(|answer| (part, answer)))
+        .unzip();
+    let check = |part, outcome| Check { day, set, part, outcome };
+
+    let actual = match solution.solve(&input, &answers.params(), &parts) {
+        Ok(actual) => actual,
+        Err(error) => {
+            let error = format!("{error:#}");
+            return Ok(parts.into_iter().map(|part| check(part, Outcome::Errored(error.clone()))).collect());
+        },
+    };
+
+    let checks = parts.into_iter().zip(expected).zip(actual)
+        .map(|((part, expected), actual)| {
+            // Multi-line answers are recorded with a trailing line break that renders don't need
+            let outcome = if expected.trim_end() == actual.trim_end() {
+                Outcome::Passed
+            } else {
+                Outcome::Failed { expected, actual }
+            };
+            check(part, outcome)
+        })
+        .collect();
+    Ok(checks)
+}
+
+/// Checks every day of the workspace at `root` against all its recorded answer sets.
+pub fn check_all(root: &Path) -> anyhow::Result<Vec<Check>> {
+    let mut checks = Vec::new();
+    for day in days::numbers() {
+        for set in ANSWER_SETS {
+            checks.extend(check_day(root, day, set)?);
+        }
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("part_1 = 24000\npart_2 = \"MCD\"", Some("24000"), Some("MCD"))]
+    #[case("part_1 = \"2=-1=0\"", Some("2=-1=0"), None)]
+    #[case("", None, None)]
+    fn parses_answers(#[case] s: &str, #[case] part_1: Option<&str>, #[case] part_2: Option<&str>) {
+        let answers = Answers::parse(s).unwrap();
+        assert_eq!(answers.get(Part::One).as_deref(), part_1);
+        assert_eq!(answers.get(Part::Two).as_deref(), part_2);
+    }
+
+    #[test]
+    fn records_answers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        std::fs::write(&path, "# Recorded\npart_1 = 24000\n").unwrap();
+
+        record_answer(&path, Part::Two, "MCD").unwrap();
+        let answers = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(answers, "# Recorded\npart_1 = 24000\npart_2 = \"MCD\"\n");
+    }
+
+    #[test]
+    fn parses_params() {
+        let answers = Answers::parse("part_1 = 26\n\n[params]\nrow = 10\n").unwrap();
+        assert_eq!(answers.params(), Params::new().with("row", 10));
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        assert!(Answers::parse("part_3 = 1").is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use std::{fmt::Write, time::Duration};
+
+use serde::{Serialize, Serializer};
+use xmas::{solution::{Part, Solution}, solver::Params};
+
+/// Spread of the durations measured over the repeats of a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "as_nanos")]
+    pub max: Duration,
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn new(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        Some(Self {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartBench {
+    pub part: u8,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// Timings of a day over several repeats, each one parsing the input again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayBench {
+    pub day: usize,
+    pub repeats: usize,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+impl DayBench {
+    /// Sum of the medians of every step.
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.parts.iter().map(|part| part.stats.median).sum::<Duration>()
+    }
+
+    pub fn part(&self, part: Part) -> Option<&Stats> {
+        self.parts.iter()
+            .find(|bench| bench.part == part.number())
+            .map(|bench| &bench.stats)
+    }
+}
+
+pub fn bench_day(
+    day: usize,
+    solution: &dyn Solution,
+    input: &str,
+    params: &Params,
+    parts: &[Part],
+    repeats: usize,
+) -> anyhow::Result<DayBench> {
+    let repeats = repeats.max(1);
+    let mut parse = Vec::with_capacity(repeats);
+    let mut part_samples = vec![Vec::with_capacity(repeats); parts.len()];
+    for _ in 0..repeats {
+        let timings = solution.time(input, params, parts)?;
+        parse.push(timings.parse);
+        for (samples, duration) in part_samples.iter_mut().zip(timings.parts) {
+            samples.push(duration);
+        }
+    }
+
+    let parts = parts.iter()
+        .zip(part_samples)
+        .filter_map(|(part, samples)| Some(PartBench { part: part.number(), stats: Stats::new(samples)? }))
+        .collect();
+    Ok(DayBench {
+        day,
+        repeats,
+        parse: Stats::new(parse).expect("At least one repeat is run"),
+        parts,
+    })
+}
+
+/// Detailed min/median/max of each step of a day.
+pub fn day_report(bench: &DayBench) -> String {
+    let mut report = format!("Day {:02} ({} runs)\n", bench.day, bench.repeats);
+    let steps = std::iter::once(("Parse".to_string(), &bench.parse))
+        .chain(bench.parts.iter().map(|part| (format!("Part {}", part.part), &part.stats)));
+    for (name, stats) in steps {
+        writeln!(
+            report,
+            "  {name:<7} min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            stats.min, stats.median, stats.max,
+        ).unwrap();
+    }
+    report
+}
+
+/// Table with the median of each step of every benchmarked day.
+pub fn summary_table(benches: &[DayBench]) -> String {
+    let mut table = format!("{:>3} | {:>10} | {:>10} | {:>10} | {:>10}\n", "Day", "Parse", "Part 1", "Part 2", "Total");
+    table.push_str(&format!("{:-<4}+{:-<12}+{:-<12}+{:-<12}+{:-<11}\n", "", "", "", "", ""));
+
+    let median = |stats: Option<&Stats>| stats
+        .map(|stats| format!("{:.2?}", stats.median))
+        .unwrap_or_else(|| "-".to_string());
+    for bench in benches {
+        writeln!(
+            table,
+            "{:>3} | {:>10} | {:>10} | {:>10} | {:>10.2?}",
+            bench.day,
+            median(Some(&bench.parse)),
+            median(bench.part(Part::One)),
+            median(bench.part(Part::Two)),
+            bench.total(),
+        ).unwrap();
+    }
+
+    let total: Duration = benches.iter().map(DayBench::total).sum();
+    writeln!(table, "{:>3} | {:>10} | {:>10} | {:>10} | {:>10.2?}", "All", "", "", "", total).unwrap();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![3, 1, 2], (1, 2, 3))]
+    #[case(vec![5], (5, 5, 5))]
+    #[case(vec![4, 1, 3, 2], (1, 3, 4))]
+    fn computes_stats(#[case] samples: Vec<u64>, #[case] expected: (u64, u64, u64)) {
+        let stats = Stats::new(samples.into_iter().map(Duration::from_millis).collect()).unwrap();
+        let (min, median, max) = expected;
+        assert_eq!(stats, Stats {
+            min: Duration::from_millis(min),
+            median: Duration::from_millis(median),
+            max: Duration::from_millis(max),
+        });
+    }
+
+    #[test]
+    fn serializes_stats_as_nanoseconds() {
+        let stats = Stats::new(vec![Duration::from_micros(1), Duration::from_micros(2)]).unwrap();
+        let json = serde_json::to_string(&stats).unwrap();
+        assert_eq!(json, r#"{"min_ns":1000,"median_ns":2000,"max_ns":2000}"#);
+    }
+}
@@ -0,0 +1,54 @@
+use xmas::{solution::Solution, visualize::Simulation};
+
+/// The solutions the runner is built with, linked in from the `day_XX` crates of this workspace.
+/// The crate name template in `aoc.toml` only tells the runner which folders to read inputs and
+/// answers from; running another year's days means listing their crates here and in `Cargo.toml`.
+const DAYS: [&dyn Solution; 25] = [
+    &day_01::Day,
+    &day_02::Day,
+    &day_03::Day,
+    &day_04::Day,
+    &day_05::Day,
+    &day_06::Day,
+    &day_07::Day,
+    &day_08::Day,
+    &day_09::Day,
+    &day_10::Day,
+    &day_11::Day,
+    &day_12::Day,
+    &day_13::Day,
+    &day_14::Day,
+    &day_15::Day,
+    &day_16::Day,
+    &day_17::Day,
+    &day_18::Day,
+    &day_19::Day,
+    &day_20::Day,
+    &day_21::Day,
+    &day_22::Day,
+    &day_23::Day,
+    &day_24::Day,
+    &day_25::Day,
+];
+
+pub fn get(day: usize) -> Option<&'static dyn Solution> {
+    day.checked_sub(1).and_then(|i| DAYS.get(i)).copied()
+}
+
+/// Numbers of every day with a registered solution.
+pub fn numbers() -> impl Iterator<Item = usize> {
+    1..=DAYS.len()
+}
+
+/// Builds a day's simulation from its input, for the days that can be visualized.
+pub type SimulationBuilder = fn(&str) -> anyhow::Result<Box<dyn Simulation>>;
+
+pub fn simulation(day: usize) -> Option<SimulationBuilder> {
+    match day {
+        14 => Some(day_14::simulation),
+        17 => Some(day_17::simulation),
+        23 => Some(day_23::simulation),
+        24 => Some(day_24::simulation),
+        _ => None,
+    }
+}
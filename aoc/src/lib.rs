@@ -0,0 +1,5 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod puzzle;
+pub mod submit;
@@ -0,0 +1,328 @@
+use std::{path::{Path, PathBuf}, time::Duration};
+
+use anyhow::{bail, Context};
+use aoc::{
+    answers::{self, AnswerSet, Answers, ANSWER_SETS},
+    bench,
+    days,
+    puzzle,
+    submit::{self, Response},
+};
+use aoc_common::{client::HttpClient, config::{self, Config}};
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use xmas::{
+    display_result,
+    point2d::Point2D,
+    record::{self, RecordFormat, RecordSettings},
+    solution::{Part, PARTS},
+    solver::Params,
+    visualize::Visualizer,
+};
+
+#[derive(Parser, Debug)]
+#[command(subcommand_negates_reqs = true, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day of the puzzle, from 1 to 25
+    #[arg(short, long, required_unless_present_any = ["check", "bench"])]
+    day: Option<usize>,
+
+    /// Part to run, both parts are run if omitted
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Puzzle input, defaults to the day's ./day_XX/input.txt
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Use the day's ./day_XX/example.txt as input instead
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Compare against the recorded answers instead, for the given day or all of them
+    #[arg(long, conflicts_with_all = ["part", "input", "example", "bench"])]
+    check: bool,
+
+    /// Time the parsing and each part instead, for the given day or all of them
+    #[arg(long)]
+    bench: bool,
+
+    /// Times each day is run when benchmarking
+    #[arg(long, default_value_t = 10, requires = "bench")]
+    repeat: usize,
+
+    /// Also write the benchmark results to this JSON file
+    #[arg(long, requires = "bench")]
+    json: Option<PathBuf>,
+
+    /// Record the day's simulations as animated GIFs into this directory, and day 22's routes as text and PNG
+    #[arg(long, requires = "day", conflicts_with_all = ["check", "bench"])]
+    record: Option<PathBuf>,
+
+    /// Keep one frame every this many simulation steps
+    #[arg(long, default_value_t = 1, requires = "record")]
+    record_every: usize,
+
+    /// Stop recording after this many simulation steps
+    #[arg(long, requires = "record")]
+    record_limit: Option<usize>,
+
+    /// Size in pixels of each tile of the recording
+    #[arg(long, default_value_t = 4, requires = "record")]
+    record_scale: usize,
+
+    /// Write numbered PNG frames instead of a GIF
+    #[arg(long, requires = "record")]
+    record_frames: bool,
+
+    /// Step through the day's simulation in the terminal instead of solving it
+    #[arg(long, requires = "day", conflicts_with_all = ["part", "check", "bench", "record"])]
+    visualize: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Submit an answer to Advent of Code, unless it's known to be wrong
+    Submit {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(short, long)]
+        day: usize,
+
+        /// Part the answer is for
+        #[arg(short, long)]
+        part: Part,
+
+        /// Answer to submit, defaults to solving the day's ./day_XX/input.txt
+        #[arg(short, long)]
+        answer: Option<String>,
+
+        /// Puzzle year, defaults to the one in aoc.toml
+        #[arg(short, long)]
+        year: Option<u64>,
+    },
+    /// Download the puzzle page and save its examples as ./day_XX/example*.txt fixtures
+    Fetch {
+        /// Day of the puzzle, from 1 to 25
+        #[arg(short, long)]
+        day: usize,
+
+        /// Puzzle year, defaults to the one in aoc.toml
+        #[arg(short, long)]
+        year: Option<u64>,
+    },
+}
+
+impl Args {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => PARTS.to_vec(),
+        }
+    }
+
+    fn record_settings(&self) -> Option<RecordSettings> {
+        let dir = self.record.clone()?;
+        Some(RecordSettings {
+            format: if self.record_frames { RecordFormat::Frames } else { RecordFormat::Gif },
+            every: self.record_every,
+            max_steps: self.record_limit,
+            scale: self.record_scale,
+            ..RecordSettings::new(dir)
+        })
+    }
+
+    fn answer_set(&self) -> AnswerSet {
+        if self.example { AnswerSet::Example } else { AnswerSet::Input }
+    }
+
+    fn input_path(&self, day: usize) -> anyhow::Result<PathBuf> {
+        match &self.input {
+            Some(input) => Ok(input.clone()),
+            None => Ok(config::crate_dir(Path::new("."), None, day)?.join(self.answer_set().input_file())),
+        }
+    }
+
+    /// Parameters of the set the input comes from, a custom input runs with the real puzzle's.
+    fn params(&self, day: usize) -> anyhow::Result<Params> {
+        match &self.input {
+            Some(_) => Ok(Params::default()),
+            None => answers::set_params(Path::new("."), day, self.answer_set()),
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    match args.command {
+        Some(Command::Submit { day, part, answer, year }) => return run_submit(day, part, answer, year),
+        Some(Command::Fetch { day, year }) => return run_fetch(day, year),
+        None => {},
+    }
+    if args.check {
+        return check(args.day);
+    }
+    if args.bench {
+        return run_bench(&args);
+    }
+
+    let day = args.day.context("No day given")?;
+    if args.visualize {
+        return run_visualizer(day, &read_input(&args.input_path(day)?)?);
+    }
+    let solution = days::get(day)
+        .with_context(|| format!("No solution found for day {}", day))?;
+    let input = read_input(&args.input_path(day)?)?;
+    if let Some(settings) = args.record_settings() {
+        println!("Recording simulations into {}", settings.dir.display());
+        record::enable(settings);
+    }
+
+    let parts = args.parts();
+    let answers = solution.solve(&input, &args.params(day)?, &parts)?;
+    for (i, (part, answer)) in parts.iter().zip(answers).enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("Part {part}:");
+        display_result(&answer);
+    }
+    Ok(())
+}
+
+fn run_visualizer(day: usize, input: &str) -> anyhow::Result<()> {
+    let simulation = days::simulation(day)
+        .with_context(|| format!("Day {} has no simulation to visualize", day))?;
+    let mut visualizer = Visualizer::new(simulation(input)?, Point2D(80, 30));
+    visualizer.run_interactive(Duration::from_millis(100))?;
+    Ok(())
+}
+
+fn read_input(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("Error reading input file {}.", path.display()))
+}
+
+fn check(day: Option<usize>) -> anyhow::Result<()> {
+    let root = Path::new(".");
+    let checks = match day {
+        Some(day) => {
+            let mut checks = Vec::new();
+            for set in ANSWER_SETS {
+                checks.extend(answers::check_day(root, day, set)?);
+            }
+            checks
+        },
+        None => answers::check_all(root)?,
+    };
+
+    for check in &checks {
+        println!("{check}");
+    }
+
+    let regressed = checks.iter().filter(|check| !check.passed()).count();
+    println!();
+    println!("{} checked, {} passed, {} regressed", checks.len(), checks.len() - regressed, regressed);
+    if regressed > 0 {
+        bail!("{regressed} answers regressed");
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct BenchReport<'a> {
+    repeats: usize,
+    days: &'a [bench::DayBench],
+}
+
+fn run_bench(args: &Args) -> anyhow::Result<()> {
+    let selected: Vec<_> = match args.day {
+        Some(day) => vec![day],
+        None => days::numbers().collect(),
+    };
+
+    let parts = args.parts();
+    let mut benches = Vec::new();
+    for day in selected {
+        let solution = days::get(day)
+            .with_context(|| format!("No solution found for day {}", day))?;
+        let input_path = args.input_path(day)?;
+        if args.day.is_none() && !input_path.exists() {
+            eprintln!("Skipping day {:02}, there's no {}", day, input_path.display());
+            continue;
+        }
+
+        let input = read_input(&input_path)?;
+        let bench = bench::bench_day(day, solution, &input, &args.params(day)?, &parts, args.repeat)
+            .with_context(|| format!("Error benchmarking day {}", day));
+        match bench {
+            Ok(bench) => {
+                println!("{}", bench::day_report(&bench));
+                benches.push(bench);
+            },
+            // A single broken day shouldn't stop the whole benchmark
+            Err(error) if args.day.is_none() => eprintln!("{error:#}\n"),
+            Err(error) => return Err(error),
+        }
+    }
+
+    print!("{}", bench::summary_table(&benches));
+
+    if let Some(json_path) = &args.json {
+        let report = BenchReport { repeats: args.repeat, days: &benches };
+        let json = serde_json::to_string_pretty(&report)?;
+        std::fs::write(json_path, json)
+            .with_context(|| format!("Error writing benchmark results to {}.", json_path.display()))?;
+    }
+    Ok(())
+}
+
+/// Year in the workspace's aoc.toml, used when none is given.
+fn year_or_default(year: Option<u64>) -> anyhow::Result<u64> {
+    if let Some(year) = year {
+        return Ok(year);
+    }
+
+    Config::load(Path::new("."))?.year
+        .context("No year given and none set in aoc.toml")
+}
+
+fn run_submit(day: usize, part: Part, answer: Option<String>, year: Option<u64>) -> anyhow::Result<()> {
+    let year = year_or_default(year)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = days::get(day)
+                .with_context(|| format!("No solution found for day {}", day))?;
+            let folder = config::crate_dir(Path::new("."), Some(year), day)?;
+            let input = read_input(&folder.join(AnswerSet::Input.input_file()))?;
+            let params = Answers::load(&folder.join(AnswerSet::Input.answers_file()))?.unwrap_or_default().params();
+            solution.solve(&input, &params, &[part])?.remove(0)
+        },
+    };
+
+    println!("Submitting {answer} for day {day} part {part}...");
+    let client = HttpClient::from_env()?;
+    match submit::submit(&client, Path::new("."), year, day, part, &answer)? {
+        Response::Verdict(verdict) => println!("The answer is {verdict}."),
+        Response::RateLimited(wait) => println!("Answered too recently, wait {}s before trying again.", wait.as_secs()),
+        Response::AlreadySolved => println!("This part was already solved."),
+    }
+    Ok(())
+}
+
+fn run_fetch(day: usize, year: Option<u64>) -> anyhow::Result<()> {
+    let year = year_or_default(year)?;
+    let client = HttpClient::from_env()?;
+    let written = puzzle::fetch_examples(&client, Path::new("."), year, day)?;
+    if written.is_empty() {
+        println!("No new examples found.");
+    }
+    for path in written {
+        println!("Saved {}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::{client::AocClient, config};
+
+use crate::answers::AnswerSet;
+
+/// Text of every `<pre><code>` block of a puzzle page, which is where examples are shown.
+pub fn extract_examples(html: &str) -> Vec<String> {
+    const START: &str = "<pre><code>";
+    const END: &str = "</code></pre>";
+
+    let mut examples = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(START) {
+        rest = &rest[start + START.len()..];
+        let Some(end) = rest.find(END) else {
+            break;
+        };
+        examples.push(decode_entities(&strip_tags(&rest[..end])));
+        rest = &rest[end + END.len()..];
+    }
+    examples
+}
+
+/// Removes the highlighting tags (`<em>`, `<span>`, ...) inside a code block.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Fixture file of the `index`th example, `example.txt` for the first one so it's part of the answer sets.
+pub fn example_path(folder: &Path, index: usize) -> PathBuf {
+    match index {
+        0 => folder.join(AnswerSet::Example.input_file()),
+        i => folder.join(format!("example_{}.txt", i + 1)),
+    }
+}
+
+/// Downloads the puzzle page and writes its examples as fixtures, keeping the ones already there.
+/// Returns the paths of the newly written fixtures.
+pub fn fetch_examples(client: &impl AocClient, root: &Path, year: u64, day: usize) -> anyhow::Result<Vec<PathBuf>> {
+    let html = client.get(&format!("/{year}/day/{day}"))?;
+    let folder = config::crate_dir(root, Some(year), day)?;
+
+    let mut written = Vec::new();
+    for (i, example) in extract_examples(&html).into_iter().enumerate() {
+        let path = example_path(&folder, i);
+        if path.exists() {
+            continue;
+        }
+        std::fs::write(&path, example)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::client::testing::serve_once;
+
+    const PAGE: &str = concat!(
+        "<article><p>For example:</p>\n",
+        "<pre><code>1000\n2000\n\n<em>4000</em>\n</code></pre>\n",
+        "<p>Then <code>inline</code> text.</p>\n",
+        "<pre><code>$ cd /\n$ ls\ndir a &amp;&amp; &lt;b&gt;\n</code></pre></article>",
+    );
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(extract_examples(PAGE), vec![
+            "1000\n2000\n\n4000\n".to_string(),
+            "$ cd /\n$ ls\ndir a && <b>\n".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn writes_example_fixtures() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("day_07")).unwrap();
+        std::fs::write(root.join("day_07/example.txt"), "kept").unwrap();
+
+        let (client, request) = serve_once("200 OK", PAGE);
+        let written = fetch_examples(&client, root, 2022, 7).unwrap();
+
+        assert_eq!(request.recv().unwrap().head[0], "GET /2022/day/7 HTTP/1.1");
+        assert_eq!(written, vec![root.join("day_07/example_2.txt")]);
+        assert_eq!(std::fs::read_to_string(root.join("day_07/example.txt")).unwrap(), "kept");
+    }
+}
@@ -0,0 +1,218 @@
+use std::{fmt::Display, path::Path, time::Duration};
+
+use anyhow::{bail, Context};
+use aoc_common::{client::AocClient, config};
+use serde::{Deserialize, Serialize};
+use xmas::solution::Part;
+
+use crate::answers::{self, AnswerSet};
+
+/// What Advent of Code said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Verdict(Verdict),
+    /// Answered too recently, the answer wasn't checked.
+    RateLimited(Duration),
+    /// The part was already solved, the answer wasn't checked.
+    AlreadySolved,
+}
+
+/// Reads the result out of the page returned after posting an answer.
+pub fn parse_response(html: &str) -> anyhow::Result<Response> {
+    let response = if html.contains("That's the right answer") {
+        Response::Verdict(Verdict::Correct)
+    } else if html.contains("You gave an answer too recently") {
+        Response::RateLimited(parse_wait(html).context("Rate limited without a wait time")?)
+    } else if html.contains("You don't seem to be solving the right level") {
+        Response::AlreadySolved
+    } else if html.contains("That's not the right answer") {
+        Response::Verdict(if html.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if html.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else {
+        bail!("Unrecognized answer response");
+    };
+    Ok(response)
+}
+
+/// Parses the "You have 1m 23s left to wait" part of a rate limited response.
+fn parse_wait(html: &str) -> Option<Duration> {
+    let end = html.find(" left to wait")?;
+    let start = html[..end].rfind("have ")? + "have ".len();
+
+    let mut seconds = 0;
+    for amount in html[start..end].split_whitespace() {
+        let (value, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 60 * 60,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a day, kept in `day_XX/guesses.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guesses {
+    #[serde(default, rename = "guess")]
+    guesses: Vec<Guess>,
+}
+
+impl Guesses {
+    pub const FILE_NAME: &'static str = "guesses.toml";
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Error reading guesses file {}.", path.display()))?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Error writing guesses file {}.", path.display()))
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict) {
+        self.guesses.push(Guess { part: part.number(), answer: answer.to_string(), verdict });
+    }
+
+    /// Returns why `answer` is known to be pointless to submit, if it is.
+    pub fn rejection(&self, part: Part, answer: &str) -> Option<String> {
+        let numeric = answer.parse::<i64>().ok();
+        self.guesses.iter()
+            .filter(|guess| guess.part == part.number())
+            .find_map(|guess| {
+                let guessed = guess.answer.parse::<i64>().ok();
+                match guess.verdict {
+                    Verdict::Correct => Some(format!("Part {part} was already solved with {}", guess.answer)),
+                    _ if guess.answer == answer => Some(format!("{answer} was already submitted, it's {}", guess.verdict)),
+                    Verdict::TooHigh if numeric.zip(guessed).is_some_and(|(n, g)| n >= g) => {
+                        Some(format!("{answer} can't be right, {} was already too high", guess.answer))
+                    },
+                    Verdict::TooLow if numeric.zip(guessed).is_some_and(|(n, g)| n <= g) => {
+                        Some(format!("{answer} can't be right, {} was already too low", guess.answer))
+                    },
+                    _ => None,
+                }
+            })
+    }
+}
+
+/// Submits an answer unless it's known to be wrong, recording the verdict next to the day's sources.
+/// Correct answers are also recorded in the day's `answers.toml` for the regression suite.
+pub fn submit(
+    client: &impl AocClient,
+    root: &Path,
+    year: u64,
+    day: usize,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<Response> {
+    let folder = config::crate_dir(root, Some(year), day)?;
+    let guesses_path = folder.join(Guesses::FILE_NAME);
+    let mut guesses = Guesses::load(&guesses_path)?;
+    if let Some(rejection) = guesses.rejection(part, answer) {
+        bail!("Not submitting: {rejection}");
+    }
+
+    let level = part.number().to_string();
+    let html = client.post_form(&format!("/{year}/day/{day}/answer"), &[("level", &level), ("answer", answer)])?;
+    let response = parse_response(&html)?;
+
+    if let Response::Verdict(verdict) = response {
+        guesses.record(part, answer, verdict);
+        guesses.save(&guesses_path)?;
+        if verdict == Verdict::Correct {
+            answers::record_answer(&folder.join(AnswerSet::Input.answers_file()), part, answer)?;
+        }
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::client::testing::serve_once;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("<p>That's the right answer! You are one gold star closer.</p>", Response::Verdict(Verdict::Correct))]
+    #[case("<p>That's not the right answer; your answer is too high.</p>", Response::Verdict(Verdict::TooHigh))]
+    #[case("<p>That's not the right answer; your answer is too low.</p>", Response::Verdict(Verdict::TooLow))]
+    #[case("<p>That's not the right answer. If you're stuck...</p>", Response::Verdict(Verdict::Wrong))]
+    #[case("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p>", Response::RateLimited(Duration::from_secs(83)))]
+    #[case("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.</p>", Response::RateLimited(Duration::from_secs(34)))]
+    #[case("<p>You don't seem to be solving the right level.  Did you already complete it?</p>", Response::AlreadySolved)]
+    fn parses_response(#[case] html: &str, #[case] expected: Response) {
+        assert_eq!(parse_response(html).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(Part::One, "100", true)]
+    #[case(Part::One, "150", true)]
+    #[case(Part::One, "20", true)]
+    #[case(Part::One, "70", false)]
+    #[case(Part::Two, "100", false)]
+    fn rejects_known_wrong_answers(#[case] part: Part, #[case] answer: &str, #[case] rejected: bool) {
+        let mut guesses = Guesses::default();
+        guesses.record(Part::One, "100", Verdict::TooHigh);
+        guesses.record(Part::One, "50", Verdict::TooLow);
+
+        assert_eq!(guesses.rejection(part, answer).is_some(), rejected);
+    }
+
+    #[test]
+    fn records_guesses() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("day_01")).unwrap();
+
+        let (client, request) = serve_once("200 OK", "<p>That's not the right answer; your answer is too low.</p>");
+        let response = submit(&client, root, 2022, 1, Part::One, "5").unwrap();
+        assert_eq!(response, Response::Verdict(Verdict::TooLow));
+        assert_eq!(request.recv().unwrap().body, "level=1&answer=5");
+
+        // Rejected before reaching the server, which only answers once
+        assert!(submit(&client, root, 2022, 1, Part::One, "4").is_err());
+
+        let guesses = Guesses::load(&root.join("day_01").join(Guesses::FILE_NAME)).unwrap();
+        assert_eq!(guesses.rejection(Part::One, "5"), Some("5 was already submitted, it's too low".to_string()));
+    }
+}
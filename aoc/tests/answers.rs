@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use aoc::answers::check_all;
+
+#[test]
+fn recorded_answers_match() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let checks = check_all(&root).unwrap();
+
+    let regressions: Vec<_> = checks.iter()
+        .filter(|check| !check.passed())
+        .map(|check| check.to_string())
+        .collect();
+    assert!(regressions.is_empty(), "Days regressed:\n{}", regressions.join("\n"));
+}
@@ -0,0 +1,151 @@
+use anyhow::Context;
+
+/// Requests to Advent of Code, as a trait so tests can talk to a local server instead.
+pub trait AocClient {
+    /// Returns the body of the page at `path`, e.g. `/2022/day/1`.
+    fn get(&self, path: &str) -> anyhow::Result<String>;
+
+    /// Posts a form to `path` and returns the body of the response page.
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String>;
+}
+
+/// Client logged in with a session cookie.
+pub struct HttpClient {
+    client: reqwest::blocking::Client,
+    base_url: String,
+    session: String,
+}
+
+impl HttpClient {
+    pub const AOC_URL: &'static str = "https://adventofcode.com";
+    /// Advent of Code asks tools that talk to it to say what they are.
+    pub const USER_AGENT: &'static str = concat!("aoc-workspace-tools/", env!("CARGO_PKG_VERSION"));
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::blocking::Client::builder()
+                .user_agent(Self::USER_AGENT)
+                .build()
+                .expect("TLS backend should be available"),
+            base_url: base_url.into(),
+            session: session.into(),
+        }
+    }
+
+    /// Uses the session cookie from the `AOC_SESSION` env variable.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let session = std::env::var("AOC_SESSION").context("Invalid AOC_SESSION env variable.")?;
+        Ok(Self::new(Self::AOC_URL, session))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url.trim_end_matches('/'), path)
+    }
+
+    fn send(&self, request: reqwest::blocking::RequestBuilder, url: &str) -> anyhow::Result<String> {
+        let response = request
+            .header("Cookie", format!("session={}", self.session))
+            .send()
+            .with_context(|| format!("Client error requesting {url}"))?
+            .error_for_status()
+            .with_context(|| format!("Server error requesting {url}"))?;
+        Ok(response.text()?)
+    }
+}
+
+impl AocClient for HttpClient {
+    fn get(&self, path: &str) -> anyhow::Result<String> {
+        let url = self.url(path);
+        self.send(self.client.get(&url), &url)
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let url = self.url(path);
+        self.send(self.client.post(&url).form(form), &url)
+    }
+}
+
+/// A stand-in for Advent of Code, for the tests of whatever uses an [`AocClient`].
+#[cfg(any(test, feature = "testing"))]
+pub mod testing {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::HttpClient;
+
+    /// A request received by [`serve_once`].
+    #[derive(Debug)]
+    pub struct Request {
+        pub head: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        /// Whether the request has the header `name: value`, ignoring case.
+        pub fn has_header(&self, name: &str, value: &str) -> bool {
+            self.head.iter().any(|line| line.eq_ignore_ascii_case(&format!("{name}: {value}")))
+        }
+    }
+
+    /// Serves a single request with the given status and body, sending back what was requested.
+    /// The client is logged in with the session `secret`.
+    pub fn serve_once(status: &'static str, body: &'static str) -> (HttpClient, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let head: Vec<String> = (&mut reader)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            let length = head.iter()
+                .find_map(|line| line.to_lowercase().strip_prefix("content-length: ").map(str::to_string))
+                .map_or(0, |length| length.parse().unwrap());
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(stream, "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+            sender.send(Request { head, body: String::from_utf8(request_body).unwrap() }).unwrap();
+        });
+        (HttpClient::new(url, "secret"), receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{testing::serve_once, *};
+
+    #[test]
+    fn gets_with_session() {
+        let (client, request) = serve_once("200 OK", "<html></html>");
+
+        assert_eq!(client.get("/2022/day/1").unwrap(), "<html></html>");
+        let request = request.recv().unwrap();
+        assert_eq!(request.head[0], "GET /2022/day/1 HTTP/1.1");
+        assert!(request.has_header("cookie", "session=secret"));
+        assert!(request.has_header("user-agent", HttpClient::USER_AGENT));
+    }
+
+    #[test]
+    fn posts_form() {
+        let (client, request) = serve_once("200 OK", "ok");
+
+        client.post_form("/2022/day/1/answer", &[("level", "1"), ("answer", "42")]).unwrap();
+        let request = request.recv().unwrap();
+        assert_eq!(request.head[0], "POST /2022/day/1/answer HTTP/1.1");
+        assert_eq!(request.body, "level=1&answer=42");
+    }
+
+    #[test]
+    fn fails_on_server_error() {
+        let (client, _request) = serve_once("404 Not Found", "");
+        assert!(client.get("/2022/day/26").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+/// Workspace defaults, read from `aoc.toml` at the workspace root by both `create_new_day` and the
+/// runner, so they agree on where each day's crate is.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: Option<u64>,
+    /// Name of the day crates, with `{year}` and `{day}` placeholders.
+    pub crate_name: String,
+    /// Template crate new days start from.
+    pub template: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            crate_name: "day_{day}".to_string(),
+            template: Self::DEFAULT_TEMPLATE.to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub const FILE_NAME: &'static str = "aoc.toml";
+    pub const DEFAULT_TEMPLATE: &'static str = "default";
+
+    /// Reads the config of the workspace at `root`, a missing file uses the defaults.
+    pub fn load(root: &Path) -> anyhow::Result<Self> {
+        let path = root.join(Self::FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Error reading {}.", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Invalid config in {}", path.display()))
+    }
+
+    /// Name of a day's crate, for `year` if given or the configured one otherwise.
+    pub fn crate_name(&self, year: Option<u64>, day: usize) -> anyhow::Result<String> {
+        let name = self.crate_name.replace("{day}", &format!("{:02}", day));
+        if !name.contains("{year}") {
+            return Ok(name);
+        }
+        match year.or(self.year) {
+            Some(year) => Ok(name.replace("{year}", &year.to_string())),
+            None => bail!("Crate names in {} need a year and none was given", Self::FILE_NAME),
+        }
+    }
+}
+
+/// Folder of a day's crate in the workspace at `root`, named as its `aoc.toml` says.
+pub fn crate_dir(root: &Path, year: Option<u64>, day: usize) -> anyhow::Result<PathBuf> {
+    Ok(root.join(Config::load(root)?.crate_name(year, day)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_config() {
+        let config: Config = toml::from_str("year = 2023\ncrate_name = \"y{year}_day_{day}\"\n").unwrap();
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.template, "default");
+        assert_eq!(config.crate_name(None, 5).unwrap(), "y2023_day_05");
+        assert_eq!(config.crate_name(Some(2021), 5).unwrap(), "y2021_day_05");
+
+        let no_year = Config { year: None, ..config };
+        assert!(no_year.crate_name(None, 5).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(toml::from_str::<Config>("crate_nmae = \"day_{day}\"\n").is_err());
+    }
+
+    #[test]
+    fn defaults_to_day_crates() {
+        assert_eq!(Config::default().crate_name(Some(2022), 12).unwrap(), "day_12");
+        assert_eq!(Config::default().crate_name(None, 12).unwrap(), "day_12");
+    }
+
+    #[test]
+    fn finds_crates_of_the_workspace() {
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(crate_dir(root.path(), None, 3).unwrap(), root.path().join("day_03"));
+
+        std::fs::write(root.path().join(Config::FILE_NAME), "year = 2022\ncrate_name = \"y{year}_day_{day}\"\n").unwrap();
+        assert_eq!(crate_dir(root.path(), None, 3).unwrap(), root.path().join("y2022_day_03"));
+    }
+}
@@ -0,0 +1,143 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use aoc_common::client::{AocClient, HttpClient};
+
+/// Somewhere a day's puzzle input can be obtained from.
+pub trait InputSource {
+    fn fetch(&self, year: u64, day: usize) -> anyhow::Result<String>;
+}
+
+/// Downloads inputs from Advent of Code.
+pub struct HttpSource<C = HttpClient>(pub C);
+
+impl HttpSource {
+    /// Uses the session cookie from the `AOC_SESSION` env variable.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let client = HttpClient::from_env().context("Set AOC_SESSION or use --offline or --input-file.")?;
+        Ok(Self(client))
+    }
+}
+
+impl<C: AocClient> InputSource for HttpSource<C> {
+    fn fetch(&self, year: u64, day: usize) -> anyhow::Result<String> {
+        self.0.get(&format!("/{year}/day/{day}/input"))
+            .with_context(|| format!("Error downloading input of day {day} ({year})"))
+    }
+}
+
+/// Imports an input already saved somewhere on disk.
+pub struct FileSource(pub PathBuf);
+
+impl InputSource for FileSource {
+    fn fetch(&self, _year: u64, _day: usize) -> anyhow::Result<String> {
+        std::fs::read_to_string(&self.0)
+            .with_context(|| format!("Error reading input file {}.", self.0.display()))
+    }
+}
+
+/// Inputs already obtained once, kept at `<dir>/<year>/day_XX.txt` so they are never downloaded twice.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn path(&self, year: u64, day: usize) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("day_{:02}.txt", day))
+    }
+
+    pub fn get(&self, year: u64, day: usize) -> Option<String> {
+        std::fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    pub fn store(&self, year: u64, day: usize, input: &str) -> anyhow::Result<()> {
+        let path = self.path(year, day);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, input)
+            .with_context(|| format!("Error caching input at {}.", path.display()))
+    }
+}
+
+impl InputSource for InputCache {
+    fn fetch(&self, year: u64, day: usize) -> anyhow::Result<String> {
+        match self.get(year, day) {
+            Some(input) => Ok(input),
+            None => bail!("Input of day {} ({}) isn't cached at {}", day, year, self.path(year, day).display()),
+        }
+    }
+}
+
+/// Gets an input from the cache, or from `source` if it's not there, caching it for next time.
+pub fn fetch_cached(
+    cache: &InputCache,
+    source: &impl InputSource,
+    year: u64,
+    day: usize,
+) -> anyhow::Result<String> {
+    if let Some(input) = cache.get(year, day) {
+        return Ok(input);
+    }
+
+    let input = source.fetch(year, day)?;
+    cache.store(year, day, &input)?;
+    Ok(input)
+}
+
+/// Writes the input where the runner expects it, next to the day's sources.
+pub fn write_input(crate_dir: &Path, input: &str) -> anyhow::Result<()> {
+    let path = crate_dir.join("input.txt");
+    std::fs::write(&path, input).with_context(|| format!("Error writing input to {}.", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::client::testing::serve_once;
+
+    #[test]
+    fn downloads_with_session() {
+        let (client, request) = serve_once("200 OK", "1\n2\n3\n");
+        let source = HttpSource(client);
+
+        let input = source.fetch(2022, 7).unwrap();
+        let request = request.recv().unwrap();
+
+        assert_eq!(input, "1\n2\n3\n");
+        assert_eq!(request.head[0], "GET /2022/day/7/input HTTP/1.1");
+        assert!(request.has_header("cookie", "session=secret"));
+    }
+
+    #[test]
+    fn fails_on_server_error() {
+        let (client, _request) = serve_once("400 Bad Request", "Please log in");
+        let source = HttpSource(client);
+
+        assert!(source.fetch(2022, 1).is_err());
+    }
+
+    #[test]
+    fn caches_fetched_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        let (client, _request) = serve_once("200 OK", "cached");
+        let source = HttpSource(client);
+
+        assert_eq!(fetch_cached(&cache, &source, 2022, 3).unwrap(), "cached");
+        // The stand-in only answers once, so this has to come from the cache
+        assert_eq!(fetch_cached(&cache, &source, 2022, 3).unwrap(), "cached");
+        assert_eq!(cache.fetch(2022, 3).unwrap(), "cached");
+    }
+
+    #[test]
+    fn fails_offline_without_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = InputCache::new(dir.path());
+        assert!(cache.fetch(2022, 4).is_err());
+    }
+}
@@ -1,62 +1,84 @@
-use std::{io::Cursor, process::Command};
+use std::path::{Path, PathBuf};
+use anyhow::Context;
+use aoc_common::config::Config;
 use clap::Parser;
 use color_print::cprintln;
-use tokio;
+use input::{fetch_cached, write_input, FileSource, HttpSource, InputCache, InputSource};
+use scaffold::Scaffold;
+use templates::template_dir;
 
-const YEAR: u64 = 2022;
+mod input;
+mod scaffold;
+mod templates;
 
 #[derive(Parser, Debug)]
 struct Args {
     day_number: usize,
+
+    /// Puzzle year, defaults to the one in aoc.toml
+    #[arg(short, long)]
+    year: Option<u64>,
+
+    /// Template crate to start from, e.g. default, grid or lines
+    #[arg(short, long)]
+    template: Option<String>,
+
+    /// Don't download the input, only use the cached one if there's any
+    #[arg(long)]
+    offline: bool,
+
+    /// Import the input from this file instead of downloading it
+    #[arg(long, conflicts_with = "offline")]
+    input_file: Option<PathBuf>,
+
+    /// Where downloaded and imported inputs are kept
+    #[arg(long, env = "AOC_CACHE_DIR", default_value = ".input_cache")]
+    cache_dir: PathBuf,
 }
 
-#[tokio::main]
-async fn main() {
+fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    // TODO: Recover from these errors and delete incomplete crates
-    let crate_name = format!("day_{:02}", args.day_number);
-    println!("🎁 Creating crate {crate_name}...");
-
-    Command::new("cargo")
-        .args(["workspaces", "create"])
-        .args(["--bin", &crate_name])
-        .args(["--name", &crate_name])
-        .args(["--edition", "2021"])
-        // .stderr(std::io::stderr())
-        .output()
-        .expect("Failed to create cargo library.");
-
-    // TODO: Use cross-platform code
-    println!("📝 Preparing files...");
-    Command::new("cp")
-        .args(["-a", "template/.", &crate_name])
-        .stderr(std::io::stderr())
-        .output()
-        .expect("Failed to copy contents from template.");
-
-    Command::new("sed")
-        .args(["-i", &format!(r#"s/name = "template"/name = "{}"/g"#, crate_name), &format!("./{}/Cargo.toml", crate_name)])
-        .stderr(std::io::stderr())
-        .output()
-        .expect("Failed to copy contents from template.");
-
-    println!("📋 Downloading input...");
-    let client = reqwest::Client::new();
-    let session = std::env::var("AOC_SESSION").expect("Invalid AOC_SESSION env variable.");
-    let result = client.get(format!("https://adventofcode.com/{}/day/{}/input", YEAR, args.day_number))
-        .header("Cookie", format!("session={}", session))
-        .send()
-        .await
-        .expect("Client error downloading input!")
-        .error_for_status()
-        .expect("Server error downloading input!");
-
-    let mut file = std::fs::File::create(format!("{crate_name}/input.txt")).unwrap();
-    let mut content = Cursor::new(result.bytes().await.unwrap());
-
-    std::io::copy(&mut content, &mut file).unwrap();
-
-    cprintln!("🎄 <green>Done!</> Don't let Santa down and don't forget to run:");
-    cprintln!("   <yellow>cd {crate_name}</>");
+    let root = Path::new(".");
+    let config = Config::load(root)?;
+    let year = args.year
+        .or(config.year)
+        .with_context(|| format!("No year given, use --year or set it in {}", Config::FILE_NAME))?;
+    let template = template_dir(root, args.template.as_deref().unwrap_or(&config.template))?;
+
+    let crate_name = config.crate_name(Some(year), args.day_number)?;
+    println!("🎁 Creating crate {crate_name} for {year}...");
+    let scaffold = Scaffold::create(root, &template, &crate_name)?;
+
+    let cache = InputCache::new(&args.cache_dir);
+    let input = match &args.input_file {
+        Some(path) => {
+            println!("📋 Importing input from {}...", path.display());
+            let input = FileSource(path.clone()).fetch(year, args.day_number)?;
+            cache.store(year, args.day_number, &input)?;
+            Some(input)
+        },
+        None if args.offline => cache.get(year, args.day_number),
+        None => match cache.get(year, args.day_number) {
+            Some(input) => Some(input),
+            None => {
+                println!("📋 Downloading input...");
+                let source = HttpSource::from_env()?;
+                Some(fetch_cached(&cache, &source, year, args.day_number)?)
+            },
+        },
+    };
+
+    match input {
+        Some(input) => write_input(scaffold.crate_dir(), &input)?,
+        None => cprintln!(
+            "⚠️  <yellow>No input cached at {}</>, add it to {crate_name}/input.txt when you're back online.",
+            cache.path(year, args.day_number).display(),
+        ),
+    }
+
+    scaffold.commit();
+    cprintln!("🎄 <green>Done!</> Don't let Santa down and don't forget to register it in <yellow>aoc/src/days.rs</> and run:");
+    cprintln!("   <yellow>cargo run --bin aoc -- --day {}</>", args.day_number);
+    Ok(())
 }
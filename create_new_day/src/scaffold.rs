@@ -0,0 +1,197 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use toml_edit::{DocumentMut, Value};
+
+/// A day crate being created from a template, removed again unless [`Scaffold::commit`] is called.
+pub struct Scaffold {
+    crate_dir: PathBuf,
+    workspace_manifest: PathBuf,
+    original_manifest: Option<String>,
+    committed: bool,
+}
+
+impl Scaffold {
+    /// Copies `template_dir` into `<root>/<crate_name>`, renames the package and adds it to the workspace members.
+    pub fn create(root: &Path, template_dir: &Path, crate_name: &str) -> anyhow::Result<Self> {
+        let crate_dir = root.join(crate_name);
+        if crate_dir.exists() {
+            bail!("{} already exists", crate_dir.display());
+        }
+
+        let mut scaffold = Self {
+            crate_dir,
+            workspace_manifest: root.join("Cargo.toml"),
+            original_manifest: None,
+            committed: false,
+        };
+
+        // Any error from here on drops the scaffold, undoing what was done so far
+        copy_dir(template_dir, &scaffold.crate_dir)
+            .with_context(|| format!("Failed to copy contents from {}.", template_dir.display()))?;
+        rename_package(&scaffold.crate_dir.join("Cargo.toml"), crate_name)?;
+
+        let manifest = std::fs::read_to_string(&scaffold.workspace_manifest)
+            .with_context(|| format!("Error reading {}.", scaffold.workspace_manifest.display()))?;
+        let updated = add_workspace_member(&manifest, crate_name)?;
+        scaffold.original_manifest = Some(manifest);
+        std::fs::write(&scaffold.workspace_manifest, updated)
+            .with_context(|| format!("Error writing {}.", scaffold.workspace_manifest.display()))?;
+
+        Ok(scaffold)
+    }
+
+    pub fn crate_dir(&self) -> &Path {
+        &self.crate_dir
+    }
+
+    /// Keeps the created crate.
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+
+    fn rollback(&self) -> std::io::Result<()> {
+        if let Some(manifest) = &self.original_manifest {
+            std::fs::write(&self.workspace_manifest, manifest)?;
+        }
+        if self.crate_dir.exists() {
+            std::fs::remove_dir_all(&self.crate_dir)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Scaffold {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+
+        eprintln!("↩️  Rolling back {}...", self.crate_dir.display());
+        if let Err(error) = self.rollback() {
+            eprintln!("Rollback failed, clean up {} manually: {error}", self.crate_dir.display());
+        }
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+fn rename_package(manifest_path: &Path, name: &str) -> anyhow::Result<()> {
+    let manifest = std::fs::read_to_string(manifest_path)
+        .with_context(|| format!("Error reading {}.", manifest_path.display()))?;
+    let mut document: DocumentMut = manifest.parse()?;
+    let package = document.get_mut("package")
+        .and_then(|package| package.as_table_like_mut())
+        .with_context(|| format!("No [package] in {}", manifest_path.display()))?;
+    package.insert("name", toml_edit::value(name));
+    std::fs::write(manifest_path, document.to_string())?;
+    Ok(())
+}
+
+/// Appends `member` to the `[workspace] members` of a manifest, one per line like the existing ones.
+pub fn add_workspace_member(manifest: &str, member: &str) -> anyhow::Result<String> {
+    let mut document: DocumentMut = manifest.parse()?;
+    let members = document.get_mut("workspace")
+        .and_then(|workspace| workspace.get_mut("members"))
+        .and_then(|members| members.as_array_mut())
+        .context("No [workspace] members list in the root Cargo.toml")?;
+
+    if members.iter().any(|existing| existing.as_str() == Some(member)) {
+        bail!("{member} is already a workspace member");
+    }
+
+    let prefix = members.iter()
+        .last()
+        .and_then(|last| last.decor().prefix())
+        .and_then(|prefix| prefix.as_str())
+        .unwrap_or("\n    ")
+        .to_string();
+    members.push_formatted(Value::from(member).decorated(prefix, ""));
+    Ok(document.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = "[workspace]\nmembers = [\n    \"xmas\",\n    \"day_01\",\n]\nresolver = \"2\"\n";
+
+    /// A workspace with a template in a temporary directory, removed once dropped.
+    fn temp_workspace() -> TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("template/src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::write(root.join("template/Cargo.toml"), "[package]\nname = \"template\"\nversion = \"0.1.0\"\n").unwrap();
+        std::fs::write(root.join("template/src/lib.rs"), "pub struct Day;\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn adds_member_on_its_own_line() {
+        let updated = add_workspace_member(MANIFEST, "day_02").unwrap();
+        assert_eq!(updated, "[workspace]\nmembers = [\n    \"xmas\",\n    \"day_01\",\n    \"day_02\",\n]\nresolver = \"2\"\n");
+    }
+
+    #[test]
+    fn rejects_existing_member() {
+        assert!(add_workspace_member(MANIFEST, "day_01").is_err());
+    }
+
+    #[test]
+    fn creates_crate_from_template() {
+        let dir = temp_workspace();
+        let root = dir.path();
+        let scaffold = Scaffold::create(root, &root.join("template"), "day_02").unwrap();
+        scaffold.commit();
+
+        let manifest = std::fs::read_to_string(root.join("day_02/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day_02\""));
+        assert_eq!(std::fs::read_to_string(root.join("day_02/src/lib.rs")).unwrap(), "pub struct Day;\n");
+        assert!(std::fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("\"day_02\""));
+    }
+
+    #[test]
+    fn rolls_back_unless_committed() {
+        let dir = temp_workspace();
+        let root = dir.path();
+        let scaffold = Scaffold::create(root, &root.join("template"), "day_02").unwrap();
+        drop(scaffold);
+
+        assert!(!root.join("day_02").exists());
+        assert_eq!(std::fs::read_to_string(root.join("Cargo.toml")).unwrap(), MANIFEST);
+    }
+
+    #[test]
+    fn rolls_back_failed_creation() {
+        let dir = temp_workspace();
+        let root = dir.path();
+        std::fs::write(root.join("Cargo.toml"), "[workspace]\n").unwrap();
+
+        assert!(Scaffold::create(root, &root.join("template"), "day_02").is_err());
+        assert!(!root.join("day_02").exists());
+    }
+
+    #[test]
+    fn keeps_existing_crate() {
+        let dir = temp_workspace();
+        let root = dir.path();
+        std::fs::create_dir(root.join("day_01")).unwrap();
+
+        assert!(Scaffold::create(root, &root.join("template"), "day_01").is_err());
+        assert!(root.join("day_01").exists());
+    }
+}
@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::bail;
+use aoc_common::config::Config;
+
+/// Folder of a named template crate, `template` for the default one and `template_<name>` otherwise.
+pub fn template_dir(root: &Path, name: &str) -> anyhow::Result<PathBuf> {
+    let dir = match name {
+        Config::DEFAULT_TEMPLATE => root.join("template"),
+        name => root.join(format!("template_{name}")),
+    };
+    if !dir.join("Cargo.toml").exists() {
+        bail!("Unknown template '{}', available ones are: {}", name, available_templates(root).join(", "));
+    }
+    Ok(dir)
+}
+
+fn available_templates(root: &Path) -> Vec<String> {
+    let mut names: Vec<_> = std::fs::read_dir(root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().join("Cargo.toml").exists())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter_map(|name| match name.as_str() {
+            "template" => Some(Config::DEFAULT_TEMPLATE.to_string()),
+            _ => name.strip_prefix("template_").map(str::to_string),
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_workspace_templates() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        assert_eq!(template_dir(&root, "default").unwrap(), root.join("template"));
+        assert_eq!(template_dir(&root, "grid").unwrap(), root.join("template_grid"));
+
+        let error = template_dir(&root, "missing").unwrap_err().to_string();
+        assert!(error.contains("default, grid, lines"), "{error}");
+    }
+}
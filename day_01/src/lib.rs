@@ -0,0 +1,41 @@
+use std::cmp::Reverse;
+
+use xmas::solver::Solver;
+
+pub struct Day;
+
+impl Solver for Day {
+    /// Calories carried by each elf, sorted from most to least.
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        // If we had yield to easily create iterators, this would've been so much easier
+        let mut total_calories = 0;
+        let mut calories_sums = Vec::new();
+        for line in input.lines() {
+            if line.is_empty() {
+                calories_sums.push(total_calories);
+                total_calories = 0;
+                continue;
+            }
+
+            let calories: i64 = line.parse()?;
+            total_calories += calories;
+        }
+        calories_sums.push(total_calories);
+        calories_sums.sort_by_key(|&c| Reverse(c));
+        Ok(calories_sums)
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result: i64 = input.iter().take(1).sum();
+        Ok(result)
+    }
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let result: i64 = input.iter().take(3).sum();
+        Ok(result)
+    }
+}
@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context};
+use xmas::solver::Solver;
 
 // Note: Could've simplified this a lot by making these shapes ints instead
 // And we calculate wins if (0 > 1 > 2 > 0...)
@@ -28,7 +32,7 @@ impl Shape {
     }
 
     pub fn score_against(&self, other: &Self) -> u64 {
-        self.base_score() + self.result_against(&other).score()
+        self.base_score() + self.result_against(other).score()
     }
 
     pub fn wins_against(&self) -> Self {
@@ -55,13 +59,18 @@ impl Shape {
         }
     }
 
-    pub fn from_str(s: &str) -> Self {
-        match s {
+}
+
+impl FromStr for Shape {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
             "A" | "X" => Self::Rock,
             "B" | "Y" => Self::Paper,
             "C" | "Z" => Self::Scissors,
-            _ => todo!("Not implemented: {}", s),
-        }
+            _ => return Err(anyhow!("Not implemented: {}", s)),
+        })
     }
 }
 
@@ -89,53 +98,60 @@ impl RoundResult {
         }
     }
 
-    pub fn from_str(s: &str) -> Self {
-        match s {
+}
+
+impl FromStr for RoundResult {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
             "A" | "X" => Self::Lose,
             "B" | "Y" => Self::Tie,
             "C" | "Z" => Self::Win,
-            _ => todo!("Not implemented: {}", s),
-        }
+            _ => return Err(anyhow!("Not implemented: {}", s)),
+        })
     }
 }
 
-fn main() {
-    part_2();
-}
+pub struct Day;
+
+impl Solver for Day {
+    /// Each round as the opponent's letter and our own column, which each part reads differently.
+    type Input = Vec<(String, String)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        input.lines()
+            .map(|line| line
+                .split_once(' ')
+                .map(|(l, r)| (l.to_string(), r.to_string()))
+                .with_context(|| format!("Invalid round: {line}")))
+            .collect()
+    }
 
-fn part_1() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
+    fn part_1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut score = 0;
+        for (opponent, player) in input {
+            let (opponent, player) = (Shape::from_str(opponent)?, Shape::from_str(player)?);
 
-    let mut score = 0;
-    for line in input.lines() {
-        let (opponent, player) = line
-            .split_once(" ")
-            .map(|(l, r)| (Shape::from_str(l), Shape::from_str(r)))
-            .unwrap();
+            // println!("{:?} against {:?} = {} + {}", player, opponent, player.base_score(), player.result_against(&opponent).score());
 
-        // println!("{:?} against {:?} = {} + {}", player, opponent, player.base_score(), player.result_against(&opponent).score());
+            score += player.score_against(&opponent);
+        }
 
-        score += player.score_against(&opponent);
+        Ok(score)
     }
 
-    println!("Result:");
-    println!("{}", score);
-}
-
-fn part_2() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
+    fn part_2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut score = 0;
+        for (opponent, expected_result) in input {
+            let (opponent, expected_result) = (Shape::from_str(opponent)?, RoundResult::from_str(expected_result)?);
 
-    let mut score = 0;
-    for line in input.lines() {
-        let (opponent, expected_result) = line
-            .split_once(" ")
-            .map(|(l, r)| (Shape::from_str(l), RoundResult::from_str(r)))
-            .unwrap();
+            let player = opponent.get_opponent_shape(&expected_result.inverted());
+            score += player.base_score() + expected_result.score();
+        }
 
-        let player = opponent.get_opponent_shape(&expected_result.inverted());
-        score += player.base_score() + expected_result.score();
+        Ok(score)
     }
-
-    println!("Result:");
-    println!("{}", score);
 }
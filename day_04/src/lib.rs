@@ -0,0 +1,65 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use anyhow::Context;
+use xmas::{interval::IntervalSet, parse, solver::Solver};
+
+pub struct AssignmentPair(RangeInclusive<u64>, RangeInclusive<u64>);
+
+impl FromStr for AssignmentPair {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn get_range(range_s: &str) -> Result<RangeInclusive<u64>, anyhow::Error> {
+            let (l_s, r_s) = range_s.split_once('-').context("No - found")?;
+            let (l, r) = (l_s.parse()?, r_s.parse()?);
+            Ok(l..=r)
+        }
+
+        let (l_s, r_s) = s.split_once(',').context("No , found")?;
+        let (left, right) = (get_range(l_s)?, get_range(r_s)?);
+        Ok(Self(left, right))
+    }
+}
+
+impl AssignmentPair {
+    fn common_sections(&self) -> IntervalSet<u64> {
+        IntervalSet::from(self.0.clone()).intersection(&IntervalSet::from(self.1.clone()))
+    }
+
+    pub fn overlap_exists(&self) -> bool {
+        let common = self.common_sections();
+        common == IntervalSet::from(self.0.clone()) || common == IntervalSet::from(self.1.clone())
+    }
+
+    pub fn partial_overlap_exists(&self) -> bool {
+        !self.common_sections().is_empty()
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<AssignmentPair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result = input.iter()
+            .filter(|a| a.overlap_exists())
+            .count();
+
+        Ok(result)
+    }
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let result = input.iter()
+            .filter(|a| a.partial_overlap_exists())
+            .count();
+
+        Ok(result)
+    }
+}
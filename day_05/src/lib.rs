@@ -1,16 +1,18 @@
-use std::{fmt::Display, str::FromStr};
-use cli_clipboard::{ClipboardContext, ClipboardProvider};
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use xmas::solver::Solver;
 
 type Crate = char;
 type CrateStack = Vec<Crate>;
 
-#[derive(Debug, Default)]
-struct CrateCollection(Vec<CrateStack>);
+#[derive(Debug, Clone, Default)]
+pub struct CrateCollection(Vec<CrateStack>);
 
 impl CrateCollection {
     pub fn from_lines<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Self {
         let mut collection = Self::default();
-        for line in lines.into_iter() {
+        for line in lines.by_ref() {
             if line.starts_with(" 1 ") {
                 break;
             }
@@ -33,7 +35,7 @@ impl CrateCollection {
 
                 collection.add_crate(_crate, index);
 
-                if chars.next() == None {
+                if chars.next().is_none() {
                     break;
                 }
 
@@ -79,13 +81,14 @@ impl CrateCollection {
     
     pub fn execute_instruction_9001(&mut self, instruction: &Instruction) {
         self.execute_instruction(instruction);
-        let mut stack = &mut self.0[instruction.to];
+        let stack = &mut self.0[instruction.to];
         let len = stack.len();
         stack[(len - instruction.quantity)..len].reverse();
     }
 }
 
-struct Instruction {
+#[derive(Debug, Clone)]
+pub struct Instruction {
     quantity: usize,
     from: usize,
     to: usize,
@@ -106,48 +109,37 @@ impl FromStr for Instruction {
     }
 }
 
-fn main() {
-    // part_1();
-    part_2();
-}
+pub struct Day;
 
-fn part_1() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
-    
-    let mut lines = input.lines();
-    let mut collection = CrateCollection::from_lines(&mut lines);
+impl Solver for Day {
+    type Input = (CrateCollection, Vec<Instruction>);
+    type Answer1 = String;
+    type Answer2 = String;
 
-    for instruction in lines.map(Instruction::from_str) {
-        let _instruction = instruction.unwrap();
-        collection.execute_instruction(&_instruction);
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut lines = input.lines();
+        let collection = CrateCollection::from_lines(&mut lines);
+        let instructions = lines
+            .map(|line| Instruction::from_str(line).map_err(|_| anyhow!("Invalid instruction: {line}")))
+            .collect::<Result<_, _>>()?;
+        Ok((collection, instructions))
     }
 
-    let result = collection.top_crates();
-    display_result(&result);
-}
-
-fn part_2() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
-    
-    let mut lines = input.lines();
-    let mut collection = CrateCollection::from_lines(&mut lines);
+    fn part_1((collection, instructions): &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut collection = collection.clone();
+        for instruction in instructions {
+            collection.execute_instruction(instruction);
+        }
 
-    for instruction in lines.map(Instruction::from_str) {
-        let _instruction = instruction.unwrap();
-        collection.execute_instruction_9001(&_instruction);
+        Ok(collection.top_crates())
     }
 
-    let result = collection.top_crates();
-    display_result(&result);
-}
-
-// TODO: Move this to common library crate
-fn display_result<T: Display>(result: &T) {
-    println!("Result:");
-    let str_result = format!("{}", result);
-    println!("{}", &str_result);
+    fn part_2((collection, instructions): &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut collection = collection.clone();
+        for instruction in instructions {
+            collection.execute_instruction_9001(instruction);
+        }
 
-    let mut clipboard = ClipboardContext::new().unwrap();
-    clipboard.set_contents(str_result.clone()).unwrap();
-    println!("Copied result to clipboard!");
+        Ok(collection.top_crates())
+    }
 }
@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use xmas::solver::Solver;
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result: usize = input.iter()
+            .flat_map(|s| find_signal_start_index(s, 4))
+            .sum();
+
+        Ok(result)
+    }
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let result: usize = input.iter()
+            .flat_map(|s| find_signal_start_index(s, 14))
+            .sum();
+
+        Ok(result)
+    }
+}
+
+fn find_signal_start_index(s: &str, length_required: usize) -> Option<usize> {
+    (length_required..s.len())
+        .map(|i| (i, &s[(i - length_required)..i]))
+        .filter(|&(_, bytes)| {
+            let mut lookup = HashSet::new();
+            for b in bytes.bytes() {
+                if lookup.contains(&b) {
+                    return false;
+                }
+                lookup.insert(b);
+            }
+            true
+        })
+        .map(|(i, _)| i)
+        .next()
+}
@@ -1,5 +1,7 @@
-use std::{fmt::{Debug, Display}, path::PathBuf, str::{FromStr, Lines}};
-use cli_clipboard::{ClipboardContext, ClipboardProvider};
+use std::{fmt::Display, path::{Path, PathBuf}, str::{FromStr, Lines}};
+
+use anyhow::Context;
+use xmas::solver::Solver;
 
 #[derive(Debug)]
 enum Node {
@@ -75,7 +77,7 @@ impl Display for Node {
 }
 
 #[derive(Debug)]
-struct Dir {
+pub struct Dir {
     name: String,
     content: Vec<Node>,
 }
@@ -87,59 +89,10 @@ impl Dir {
 
     fn from_commands(lines: &mut Lines) -> Self {
         let mut root_dir = Dir::new("/");
-        let mut cur_path = PathBuf::from("/"); cur_path = PathBuf::from("/");
+        let mut cur_path = PathBuf::from("/");
         
         let mut cur_line = lines.next();
-        loop {
-            let _cur_line = match cur_line {
-                Some(l) => l,
-                None => break,
-            };
-
-            let mut split = _cur_line.split_whitespace();
-            assert_eq!(split.next(), Some("$"));
-            match split.next() {
-                Some("cd") => {
-                    // println!("{}", _cur_line);
-                    let path = split.next().expect("No path given");
-                    if path == ".." { // We could have a chain of .. paths but the challenge doesn't require it
-                        cur_path.pop();
-                    } else {
-                        cur_path.push(path);
-                    }
-                    // println!("Cur path: {:?}", cur_path);
-                },
-                Some("ls") => {
-                    // println!("{}", _cur_line);
-                    let dir = root_dir.get_dir_mut(&cur_path);
-                    loop {
-                        cur_line = lines.next();
-                        let node = match cur_line {
-                            Some(s) if s.starts_with("$") => break,
-                            Some(s) => {
-                                // println!("Found: {}", s);
-                                s.parse::<Node>().expect("Failed to parse node")
-                            },
-                            None => break,
-                        };
-                        dir.content.push(node);
-                    }
-                    continue;
-                },
-                Some(cmd) => panic!("Unrecognized command: {}", cmd),
-                None => panic!("No command given"),
-            }
-
-            cur_line = lines.next();
-        }
-        
-        let mut cur_line = lines.next();
-        loop {
-            let _cur_line = match cur_line {
-                Some(l) => l,
-                None => break,
-            };
-
+        while let Some(_cur_line) = cur_line {
             let mut split = _cur_line.split_whitespace();
             assert_eq!(split.next(), Some("$"));
             match split.next() {
@@ -183,7 +136,7 @@ impl Dir {
         self.content.iter().map(Node::total_size).sum()
     }
 
-    fn get_dir_mut(&mut self, path: &PathBuf) -> &mut Self {
+    fn get_dir_mut(&mut self, path: &Path) -> &mut Self {
         let mut dir = self;
         for component in path.components() {
             if component.as_os_str() == "/" {
@@ -231,7 +184,7 @@ impl Dir {
         let self_size = self.total_size();
         let mut current_min = min;
         if self_size >= limit && (current_min.is_none() || current_min.is_some_and(|min| self_size < min)) {
-            println!("Found dir {} of size {}", self.name, self_size);
+            // println!("Found dir {} of size {}", self.name, self_size);
             current_min = Some(self_size);
         }
 
@@ -249,47 +202,37 @@ impl Display for Dir {
     }
 }
 
-fn main() {
-    // part_1();
-    part_2();
-}
-
-fn part_1() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
-    let root_dir = Dir::from_commands(&mut input.lines());
+pub struct Day;
 
-    // println!("Folder structure:");
-    // println!("{}", &root_dir);
+impl Solver for Day {
+    type Input = Dir;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    let result = root_dir.get_deletable_dirs_size_repeated(100_000);
-    display_result(&result);
-}
-
-fn part_2() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
-    let root_dir = Dir::from_commands(&mut input.lines());
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Dir::from_commands(&mut input.lines()))
+    }
 
-    const TOTAL_SIZE: u64 = 70_000_000;
-    const REQUIRED_SIZE: u64 = 30_000_000;
-    let occupied = root_dir.total_size();
-    let current_free = TOTAL_SIZE - occupied;
-    let to_delete: u64 = REQUIRED_SIZE - current_free;
+    fn part_1(root_dir: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        // println!("Folder structure:");
+        // println!("{}", &root_dir);
 
-    println!("{} bytes occupied", occupied);
-    println!("{} bytes free", current_free);
-    println!("{} to delete", to_delete);
+        let result = root_dir.get_deletable_dirs_size_repeated(100_000);
+        Ok(result)
+    }
 
-    let result = root_dir.get_smallest_deletable_size(to_delete);
-    display_result(&result);
-}
+    fn part_2(root_dir: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        const TOTAL_SIZE: u64 = 70_000_000;
+        const REQUIRED_SIZE: u64 = 30_000_000;
+        let occupied = root_dir.total_size();
+        let current_free = TOTAL_SIZE - occupied;
+        let to_delete: u64 = REQUIRED_SIZE - current_free;
 
-// TODO: Move this to common library crate
-fn display_result<T: Debug>(result: &T) {
-    println!("Result:");
-    let str_result = format!("{:?}", result);
-    println!("{}", &str_result);
+        // println!("{} bytes occupied", occupied);
+        // println!("{} bytes free", current_free);
+        // println!("{} to delete", to_delete);
 
-    let mut clipboard = ClipboardContext::new().unwrap();
-    clipboard.set_contents(str_result.clone()).unwrap();
-    println!("Copied result to clipboard!");
+        let result = root_dir.get_smallest_deletable_size(to_delete).context("No deletable dir found")?;
+        Ok(result)
+    }
 }
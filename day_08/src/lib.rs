@@ -0,0 +1,93 @@
+use std::{ops, str::FromStr};
+
+use anyhow::Context;
+use xmas::{direction::{Direction, DIRECTIONS}, map2d::{Map2D, ParseMapError}, point2d::Point2D, solver::Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TreeHeight(u8);
+
+impl TryFrom<u8> for TreeHeight {
+    type Error = ();
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        byte.is_ascii_digit().then(|| Self(byte - b'0')).ok_or(())
+    }
+}
+
+impl From<TreeHeight> for char {
+    fn from(height: TreeHeight) -> Self {
+        char::from(b'0' + height.0)
+    }
+}
+
+pub struct ForestMap {
+    map: Map2D<TreeHeight>,
+}
+
+impl FromStr for ForestMap {
+    type Err = ParseMapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Map2D::from_str(s)?;
+        Ok(ForestMap { map })
+    }
+}
+
+impl ForestMap {
+    pub fn is_visible_towards(&self, from: Point2D, towards: Direction) -> bool {
+        let target_height = *self.map.get_tile(from).unwrap();
+        self.map.ray(from, towards).all(|(_, &height)| height < target_height)
+    }
+
+    pub fn is_visible(&self, from: Point2D) -> bool {
+        DIRECTIONS.iter().any(|&dir| self.is_visible_towards(from, dir))
+    }
+
+    pub fn visible_count(&self) -> usize {
+        self.map.iter_points().filter(|&p| self.is_visible(p)).count()
+    }
+
+    pub fn get_visible_trees(&self, from: Point2D, towards: Direction) -> usize {
+        let target_height = *self.map.get_tile(from).unwrap();
+        let mut count = 0;
+        for (_, &height) in self.map.ray(from, towards) {
+            count += 1;
+            if height >= target_height {
+                break;
+            }
+        }
+        count
+    }
+
+    pub fn scenic_score_at(&self, from: Point2D) -> Option<usize> {
+        DIRECTIONS.iter()
+            .map(|&dir| self.get_visible_trees(from, dir))
+            .reduce(ops::Mul::mul)
+    }
+
+    pub fn find_best_scenic_score(&self) -> Option<usize> {
+        self.map.iter_points().flat_map(|p| self.scenic_score_at(p)).max()
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = ForestMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(ForestMap::from_str(input)?)
+    }
+
+    fn part_1(map: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result = map.visible_count();
+        Ok(result)
+    }
+
+    fn part_2(map: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let result = map.find_best_scenic_score().context("Empty forest")?;
+        Ok(result)
+    }
+}
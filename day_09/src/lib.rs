@@ -0,0 +1,143 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use xmas::{direction::Direction, parse, point2d::Point2D, solver::Solver};
+
+#[derive(Debug, Default, Clone)]
+struct RopeSegment {
+    head: Point2D,
+    tail_relative: Point2D,
+}
+
+impl RopeSegment {
+    pub fn at_starting_position() -> Self {
+        Self::default()
+    }
+
+    pub fn tail_world(&self) -> Point2D {
+        self.head + self.tail_relative
+    }
+
+    pub fn move_towards(&self, direction: Direction) -> Self {
+        let new_head = self.head + direction.as_point();
+        let new_tail_relative = self.tail_relative - direction.as_point();
+
+        Self { head: new_head, tail_relative: Self::correct_tail_relative(new_tail_relative) }
+    }
+
+    /// A tail that's no longer touching the head takes a single step towards it, diagonally if
+    /// needed, so (1, 2) becomes (0, 1) but never (1, 1).
+    pub fn correct_tail_relative(relative: Point2D) -> Point2D {
+        if relative.chebyshev_magnitude() > 1 {
+            relative - relative.signum()
+        } else {
+            relative
+        }
+    }
+}
+
+struct Rope {
+    segments: Vec<Point2D>,
+}
+
+impl Rope {
+    pub fn of_length(len: usize) -> Self {
+        Self { segments: vec![Point2D::ZERO; len] }
+    }
+
+    pub fn move_towards(&mut self, direction: Direction) {
+        let mut leading_pos = self.head_pos() + direction.as_point();
+        *self.segments.first_mut().unwrap() = leading_pos;
+        // println!("Moved head at: {}", leading_pos);
+
+        for follower_pos in self.segments.iter_mut().skip(1) {
+            let relative_pos = *follower_pos - leading_pos;
+            let corrected_relative_pos = RopeSegment::correct_tail_relative(relative_pos);
+            // println!("Correction: {} -> {}", relative_pos, corrected_relative_pos);
+            if relative_pos == corrected_relative_pos {
+                break; // No need to recalculate the other segments
+            }
+
+            let new_pos = leading_pos + corrected_relative_pos;
+            *follower_pos = new_pos;
+            leading_pos = new_pos;
+        }
+    }
+
+    pub fn head_pos(&self) -> Point2D {
+        *self.segments.first().unwrap()
+    }
+
+    pub fn tail_pos(&self) -> Point2D {
+        *self.segments.last().unwrap()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub amount: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dir_s, amount_s) = s.split_once(' ').context("Should only have one space")?;
+        let direction = match dir_s {
+            "U" => Direction::Up,
+            "R" => Direction::Left,
+            "D" => Direction::Down,
+            "L" => Direction::Right,
+            c => return Err(anyhow!("Unknown direction: {}", c)),
+        };
+
+        let amount = amount_s.parse().context("Error parsing amount")?;
+        Ok(Self { direction, amount })
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part_1(instructions: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut rope = RopeSegment::at_starting_position();
+        let mut visited = HashSet::new();
+        visited.insert(rope.tail_world());
+        for instruction in instructions {
+            for _ in 0..instruction.amount {
+                rope = rope.move_towards(instruction.direction);
+                if visited.insert(rope.tail_world()) {
+                    // println!("Added point: T{}--H{}", rope.tail_world(), rope.head);
+                }
+            }
+        }
+
+        Ok(visited.len())
+    }
+
+    fn part_2(instructions: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut rope = Rope::of_length(10);
+        let mut visited = HashSet::new();
+        visited.insert(rope.tail_pos());
+
+        for instruction in instructions {
+            for _ in 0..instruction.amount {
+                rope.move_towards(instruction.direction);
+                if visited.insert(rope.tail_pos()) {
+                    // println!("Added point: T{}--H{}", rope.tail_pos(), rope.head_pos());
+                }
+            }
+        }
+
+        Ok(visited.len())
+    }
+}
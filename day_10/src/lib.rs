@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use xmas::{parse, solver::Solver, vm::{self, Flow, InstructionSet, Machine, ParseInstructionError, Peripheral, Registers}};
+
+/// The only register of the CPU.
+const X: usize = 0;
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Noop,
+    AddX(i64),
+}
+
+const INSTRUCTION_SET: InstructionSet<Instruction> = InstructionSet::new(&[
+    ("noop", |_| Ok(Instruction::Noop)),
+    ("addx", |operands| Ok(Instruction::AddX(vm::operand(operands, 0)?))),
+]);
+
+impl FromStr for Instruction {
+    type Err = ParseInstructionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        INSTRUCTION_SET.decode(s)
+    }
+}
+
+impl vm::Instruction for Instruction {
+    fn cycles(&self) -> u64 {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::AddX(_) => 2,
+        }
+    }
+
+    fn execute(&self, registers: &mut Registers) -> Flow {
+        match self {
+            Instruction::Noop => {},
+            Instruction::AddX(amount) => registers.add(X, *amount),
+        }
+        Flow::Next
+    }
+}
+
+fn cpu(instructions: &[Instruction]) -> Machine<Instruction> {
+    Machine::new(instructions.to_vec(), Registers::new(&["X"]).with(X, 1))
+}
+
+/// Adds up the signal strength during the 20th cycle and every 40 cycles after it.
+#[derive(Debug, Default)]
+struct SignalProbe {
+    total: i64,
+}
+
+impl Peripheral for SignalProbe {
+    fn tick(&mut self, cycle: u64, registers: &Registers) {
+        if cycle >= 20 && (cycle - 20).is_multiple_of(40) {
+            self.total += cycle as i64 * registers.get(X);
+        }
+    }
+}
+
+/// Screen drawing a pixel each cycle, lit when the 3 wide sprite centered on X covers it.
+#[derive(Debug, Default)]
+struct Crt {
+    render: String,
+    column: u64,
+}
+
+impl Crt {
+    const WIDTH: u64 = 40;
+    /// Pixels the sprite covers on each side of X, making it 3 wide.
+    const SPRITE_REACH: u64 = 1;
+}
+
+impl Peripheral for Crt {
+    fn tick(&mut self, _cycle: u64, registers: &Registers) {
+        let draw = registers.get(X).abs_diff(self.column as i64) <= Self::SPRITE_REACH;
+        self.render.push(if draw { '#' } else { '.' });
+
+        self.column += 1;
+        if self.column >= Self::WIDTH {
+            self.column = 0;
+            self.render.push('\n');
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part_1(instructions: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut probe = SignalProbe::default();
+        cpu(instructions).run(&mut probe)?;
+        Ok(probe.total)
+    }
+
+    fn part_2(instructions: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut crt = Crt::default();
+        cpu(instructions).run(&mut crt)?;
+        Ok(crt.render)
+    }
+}
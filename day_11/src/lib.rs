@@ -1,6 +1,8 @@
 use num::integer::lcm;
-use std::{cmp::Reverse, num::ParseIntError, ops::Mul, str::FromStr};
-use xmas::display_result;
+use std::{cmp::Reverse, num::ParseIntError, str::FromStr};
+
+use anyhow::anyhow;
+use xmas::{parse::{self, Line, ParseError}, solver::Solver};
 
 type Item = u64;
 
@@ -37,15 +39,15 @@ enum Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s.bytes().next() {
-            Some(b'+') => Self::Add(Value::from_str(s[1..].trim()).unwrap()),
-            Some(b'*') => Self::Multiply(Value::from_str(s[1..].trim()).unwrap()),
-            Some(c) => panic!("Unknown operator: {:?}", char::from_u32(c as u32)),
-            None => panic!("Empty string"),
-        })
+        match s.bytes().next() {
+            Some(b'+') => Ok(Self::Add(Value::from_str(s[1..].trim())?)),
+            Some(b'*') => Ok(Self::Multiply(Value::from_str(s[1..].trim())?)),
+            Some(c) => Err(anyhow!("Unknown operator: {:?}", char::from(c))),
+            None => Err(anyhow!("Empty operation")),
+        }
     }
 }
 
@@ -59,7 +61,7 @@ impl Operation {
 }
 
 #[derive(Debug, Clone)]
-struct Monkey {
+pub struct Monkey {
     items: Vec<Item>,
     operation: Operation,
     divisible_test: Item,
@@ -69,49 +71,21 @@ struct Monkey {
 }
 
 impl Monkey {
-    pub fn from_lines<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Option<Self> {
-        let header = match lines.skip_while(|l| l.is_empty()).next() {
-            Some(s) => s,
-            None => return None,
+    pub fn from_paragraph(paragraph: &[Line<'_>]) -> Result<Self, ParseError> {
+        let [header, items_line, operation_line, test_line, true_line, false_line] = paragraph else {
+            return Err(paragraph[0].error(format!("Expected 6 lines for a monkey, found {}", paragraph.len())));
         };
-        assert!(header.starts_with("Monkey"));
-
-        let starting_items_line = lines.next().unwrap().trim_start();
-        const STARTING_ITEMS: &str = "Starting items:";
-        assert!(starting_items_line.starts_with(STARTING_ITEMS));
-        let items = starting_items_line
-            .trim_start_matches(STARTING_ITEMS)
-            .split(',')
-            .map(str::trim)
-            .map(str::parse::<Item>)
-            .collect::<Result<Vec<_>, _>>()
-            .unwrap();
-
-        let operation_line = lines.next().unwrap().trim_start();
-        const OPERATION: &str = "Operation: new = old";
-        assert!(operation_line.starts_with(OPERATION));
-        let operation =
-            Operation::from_str(operation_line.trim_start_matches(OPERATION).trim()).unwrap();
-
-        let divisible_test = lines.next()
-            .and_then(|l| l.split_whitespace().last())
-            .unwrap()
-            .parse::<Item>()
-            .unwrap();
-
-        let on_true_pass_to = lines.next()
-            .and_then(|l| l.split_whitespace().last())
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        let on_false_pass_to = lines.next()
-            .and_then(|l| l.split_whitespace().last())
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        Some(Self {
+        field(header, "Monkey")?;
+
+        field(items_line, "Starting items:")?;
+        let items = items_line.ints::<Item>()?;
+
+        let operation = operation_line.parse_part(field(operation_line, "Operation: new = old")?)?;
+        let divisible_test = test_line.parse_part(field(test_line, "Test: divisible by")?)?;
+        let on_true_pass_to = true_line.parse_part(field(true_line, "If true: throw to monkey")?)?;
+        let on_false_pass_to = false_line.parse_part(field(false_line, "If false: throw to monkey")?)?;
+
+        Ok(Self {
             items,
             operation,
             divisible_test,
@@ -123,7 +97,7 @@ impl Monkey {
 
     pub fn calculate_throw_with_decay(&self, item: Item, worry_decay: Item) -> Throw {
         let new_value = self.operation.apply(item) / worry_decay;
-        Throw { item: new_value, to: self.get_target(new_value % self.divisible_test == 0) }
+        Throw { item: new_value, to: self.get_target(new_value.is_multiple_of(self.divisible_test)) }
     }
 
     pub fn get_target(&self, condition: bool) -> usize {
@@ -132,7 +106,7 @@ impl Monkey {
 }
 
 #[derive(Debug, Clone)]
-struct MonkeyGroup {
+pub struct MonkeyGroup {
     monkeys: Vec<Monkey>,
     rounds: u32,
     // Needed to limit the max number and avoid overflows
@@ -140,18 +114,24 @@ struct MonkeyGroup {
     lcm: Item,
 }
 
+/// What follows `prefix` in the line, trimmed.
+fn field<'a>(line: &Line<'a>, prefix: &str) -> Result<&'a str, ParseError> {
+    line.text.trim_start()
+        .strip_prefix(prefix)
+        .map(str::trim)
+        .ok_or_else(|| line.error(format!("Expected {prefix:?}")))
+}
+
 impl FromStr for MonkeyGroup {
-    type Err = ();
+    type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let monkeys = {
-            let mut lines = s.lines();
-            let mut monkeys = vec![];
-            while let Some(monkey) = Monkey::from_lines(&mut lines) {
-                monkeys.push(monkey);
-            }
-            monkeys
-        };
+        let monkeys = parse::paragraphs(s)
+            .map(|paragraph| Monkey::from_paragraph(&paragraph))
+            .collect::<Result<Vec<_>, _>>()?;
+        if monkeys.is_empty() {
+            return Err(anyhow!("No monkeys found"));
+        }
         Ok(MonkeyGroup::new(monkeys))
     }
 }
@@ -190,43 +170,47 @@ impl MonkeyGroup {
             .map(|m| m.inspection_count)
             .collect::<Vec<_>>();
         levels.sort_unstable_by_key(|&i| Reverse(i));
-        println!("Scores: {:?}", levels);
+        // println!("Scores: {:?}", levels);
         levels[0] * levels[1]
     }
 }
 
-struct Throw {
+pub struct Throw {
     item: Item,
     to: usize,
 }
 
-fn main() {
-    // part_1();
-    part_2();
-}
+pub struct Day;
 
-fn part_1() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
-    let mut monkeys = MonkeyGroup::from_str(&input).unwrap();
-    for _ in 0..20 {
-        monkeys.play_round(3);
+impl Solver for Day {
+    type Input = MonkeyGroup;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        MonkeyGroup::from_str(input)
     }
-    // println!("{:#?}", monkeys);
 
-    let result = monkeys.monkey_business_level();
-    display_result(&result);
-}
+    fn part_1(monkeys: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut monkeys = monkeys.clone();
+        for _ in 0..20 {
+            monkeys.play_round(3);
+        }
+        // println!("{:#?}", monkeys);
 
-fn part_2() {
-    let input = std::fs::read_to_string("./input.txt").expect("Error reading input file.");
-    let mut monkeys = MonkeyGroup::from_str(&input).unwrap();
-    println!("LCM: {}", monkeys.lcm);
-    println!("Mul: {}", monkeys.monkeys.iter().map(|m| m.divisible_test).reduce(Mul::mul).unwrap());
-    for _ in 0..10_000 {
-        monkeys.play_round(1);
+        let result = monkeys.monkey_business_level();
+        Ok(result)
     }
-    // println!("{:#?}", monkeys);
 
-    let result = monkeys.monkey_business_level();
-    display_result(&result);
+    fn part_2(monkeys: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut monkeys = monkeys.clone();
+        // println!("LCM: {}", monkeys.lcm);
+        for _ in 0..10_000 {
+            monkeys.play_round(1);
+        }
+        // println!("{:#?}", monkeys);
+
+        let result = monkeys.monkey_business_level();
+        Ok(result)
+    }
 }
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+use xmas::{map2d::{Map2D, ParseMapError}, point2d::Point2D, search::{Found, Search, SearchError}, solver::Solver};
+
+pub struct NavigationMap {
+    start: Point2D,
+    destination: Point2D,
+    map: Map2D,
+}
+
+impl FromStr for NavigationMap {
+    type Err = ParseMapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Map2D::from_str(s)?;
+        Ok(Self::new(map))
+    }
+}
+
+impl NavigationMap {
+    pub fn new(mut map: Map2D) -> Self {
+        let start = map.find(&b'S').expect("No starting point found!");
+        *map.get_tile_mut(start).unwrap() = b'a';
+
+        let destination = map.find(&b'E').expect("No destination point found!");
+        *map.get_tile_mut(destination).unwrap() = b'z';
+
+        Self {
+            start,
+            destination,
+            map,
+        }
+    }
+
+    pub fn find_path(&self) -> Result<Found<Point2D, usize>, SearchError> {
+        self.find_path_from(self.start)
+    }
+
+    pub fn find_path_from(&self, start: Point2D) -> Result<Found<Point2D, usize>, SearchError> {
+        const MOVE_COST: usize = 1;
+        let destination = self.destination;
+        Search::by_node().astar(
+            start,
+            |&from| self.find_navigatable_tiles(from).map(|p| (p, MOVE_COST)),
+            |cur| cur.manhattan_distance(destination),
+            |&cur| cur == destination,
+        )
+    }
+
+    pub fn lowest_points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        self.map.find_all(&b'a')
+    }
+
+    fn find_navigatable_tiles(&self, from: Point2D) -> impl Iterator<Item = Point2D> + '_ {
+        const MAX_CLIMB_HEIGHT: u8 = 1;
+        let cur_height = *self.map.get_tile(from).expect("from must be inside the map");
+        let target_height = cur_height + MAX_CLIMB_HEIGHT;
+
+        self.map.neighbors(from)
+            .filter(move |&(_, &tile_height)| tile_height <= target_height)
+            .map(|(p, _)| p)
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = NavigationMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(NavigationMap::from_str(input)?)
+    }
+
+    fn part_1(map: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        Ok(map.find_path()?.cost)
+    }
+
+    fn part_2(map: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let found = map.lowest_points()
+            .flat_map(|start| map.find_path_from(start))
+            .min_by_key(|found| found.cost)
+            .ok_or(SearchError::NotFound)?;
+
+        Ok(found.cost)
+    }
+}
@@ -1,10 +1,10 @@
 use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
 use anyhow::{anyhow, Context};
-use xmas::display_result;
+use xmas::{parse, solver::Solver};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Packet {
+pub enum Packet {
     Value(i64),
     List(Vec<Self>),
 }
@@ -135,92 +135,66 @@ impl Ord for Packet {
     }
 }
 
-fn main() -> anyhow::Result<()> {
-    part_1()?;
-    println!();
-    part_2()?;
-    Ok(())
-}
+pub struct Day;
 
-fn part_1() -> anyhow::Result<()> {
-    println!("Part 1:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
-
-    let mut lines = input.lines();
-    let mut result = 0;
-    let mut index = 0;
-    loop {
-        index += 1;
-
-        let (left, _left_line) = match lines.next() {
-            Some(line) if line.is_empty() => break,
-            Some(line) => (Packet::from_str(line)?, line),
-            None => break,
-        };
-        let right_line = lines.next().ok_or_else(|| anyhow!("No line found for right value"))?;
-        let right = right_line.parse::<Packet>()?;
-
-        // println!();
-        // println!("Processing index {index}");
-        // println!("Comparing:");
-        // println!("{_left_line}");
-        // println!("{right_line}");
-
-        let comparison = left.cmp(&right);
-        let is_in_order = comparison.is_le();
-        // println!("Result: {comparison:?} = {is_in_order}");
-        if is_in_order {
-            // println!();
-            // println!("Adding: {}", index);
-            // println!("{}", _left_line);
-            // println!("vs");
-            // println!("{}", right_line);
-            result += index;
-        } else {
-            // println!();
-            // println!("Didn't add: {}", index);
-            // println!("{}", _left_line);
-            // println!("vs");
-            // println!("{}", right_line);
-        }
-        
-        lines.next();
+impl Solver for Day {
+    type Input = Vec<Packet>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::lines(input)
+            .filter(|line| !line.text.is_empty())
+            .map(|line| line.parse())
+            .collect::<Result<_, _>>()?)
     }
 
-    println!();
-    display_result(&result);
-    Ok(())
-}
+    fn part_1(packets: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut result = 0;
+        for (i, pair) in packets.chunks(2).enumerate() {
+            let index = i + 1;
+            let [left, right] = pair else {
+                return Err(anyhow!("No line found for right value"));
+            };
+
+            let comparison = left.cmp(right);
+            let is_in_order = comparison.is_le();
+            // println!("Result: {comparison:?} = {is_in_order}");
+            if is_in_order {
+                // println!("Adding: {}", index);
+                // println!("{}", left);
+                // println!("vs");
+                // println!("{}", right);
+                result += index;
+            }
+        }
 
-fn part_2() -> anyhow::Result<()> {
-    println!("Part 2:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
+        Ok(result)
+    }
 
-    let mut packets = input.lines()
-        .filter(|s| !s.is_empty())
-        .map(Packet::from_str)
-        .collect::<Result<Vec<_>, _>>()?;
+    fn part_2(packets: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut packets = packets.clone();
 
-    let packet_1 = Packet::from_str("[[2]]")?;
-    let packet_2 = Packet::from_str("[[6]]")?;
+        let packet_1 = Packet::from_str("[[2]]")?;
+        let packet_2 = Packet::from_str("[[6]]")?;
 
-    packets.push(packet_1.clone());
-    packets.push(packet_2.clone());
+        packets.push(packet_1.clone());
+        packets.push(packet_2.clone());
 
-    packets.sort();
+        packets.sort();
 
-    let packet_1_index = packets.iter().position(|p| p == &packet_1).unwrap() + 1;
-    let packet_2_index = packets.iter().position(|p| p == &packet_2).unwrap() + 1;
+        let packet_1_index = packets.iter().position(|p| p == &packet_1).unwrap() + 1;
+        let packet_2_index = packets.iter().position(|p| p == &packet_2).unwrap() + 1;
 
-    // for (i, packet) in packets.iter().enumerate() {
-    //     print!("{packet}");
-    //     if packet == &packet_1 || packet == &packet_2 {
-    //         print!(" <---- {}", i + 1);
-    //     }
-    //     println!();
-    // }
+        // for (i, packet) in packets.iter().enumerate() {
+        //     print!("{packet}");
+        //     if packet == &packet_1 || packet == &packet_2 {
+        //         print!(" <---- {}", i + 1);
+        //     }
+        //     println!();
+        // }
 
-    let result = packet_1_index * packet_2_index;
-    display_result(&result);
-    Ok(())
+        let result = packet_1_index * packet_2_index;
+        Ok(result)
+    }
 }
@@ -0,0 +1,265 @@
+use std::{iter, str::FromStr};
+
+use anyhow::Context;
+use xmas::{image::{Palette, BLACK, RED}, map2d::Map2D, parse, point2d::Point2D, record::Recorder, solver::Solver, visualize::Simulation};
+
+#[derive(Debug, Clone)]
+pub struct Line(Vec<Point2D>);
+
+impl Line {
+    fn get_max(&self, current: Option<Point2D>) -> Option<Point2D> {
+        let mut cur = current;
+        for point in self.0.iter() {
+            if let Some(_cur) = cur {
+                cur = Some(point.max(_cur))
+            } else {
+                cur = Some(*point);
+            }
+        }
+        cur
+    }
+
+    fn get_min(&self, current: Option<Point2D>) -> Option<Point2D> {
+        let mut cur = current;
+        for point in self.0.iter() {
+            if let Some(_cur) = cur {
+                cur = Some(point.min(_cur))
+            } else {
+                cur = Some(*point);
+            }
+        }
+        cur
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        self.0.first()
+            .into_iter()
+            .cloned()
+            .chain((1..self.0.len())
+                .map(|i| (self.0[i - 1], self.0[i]))
+                .flat_map(|(from, to)| Self::get_points(from, to))
+            )
+    }
+
+    fn get_points(from: Point2D, to: Point2D) -> impl Iterator<Item = Point2D> {
+        let towards = from.try_get_direction_towards(to);
+        let mut cur = from;
+        let mut step = 0;
+        iter::from_fn(move || {
+            let (dir, amount) = match towards {
+                Some(v) => v,
+                None => return None,
+            };
+
+            cur += dir.as_point();
+            step += 1;
+            if step <= amount {
+                Some(cur)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl FromStr for Line {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s.split("->")
+            .map(str::trim)
+            .map(Point2D::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(line))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CollisionResult {
+    Collision,
+    Air,
+    Outside,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+    Source,
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'O',
+            Tile::Source => '+',
+        }
+    }
+}
+
+pub struct CaveMap {
+    map: Map2D<Tile>,
+    sand_source: Point2D,
+    /// Gets a frame each time a unit of sand comes to rest.
+    recorder: Option<Recorder>,
+}
+
+impl FromStr for CaveMap {
+    type Err = anyhow::Error;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().map(Line::from_str).collect::<Result<Vec<_>, _>>()?;
+        Self::try_new(&lines, false)
+    }
+}
+
+impl CaveMap {
+    const SAND_SOURCE: Point2D = Point2D(500, 0);
+
+    /// With a `floor`, the map is wide enough for the sand to pile up to the source without falling out.
+    pub fn try_new(lines: &[Line], floor: bool) -> Result<Self, anyhow::Error> {
+        let mut option_min = None;
+        let mut option_max = None;
+        for line in lines.iter() {
+            option_min = line.get_min(option_min);
+            option_max = line.get_max(option_max);
+        }
+        let mut min = option_min.map(|p| Point2D(p.0, 0)).context("No min/max found")?;
+        let mut max = option_max.context("No min/max found")? + Point2D(1, 1);
+
+        let floor_y = max.1 + 1;
+        if floor {
+            // Sand can't spread further sideways than it can fall
+            min.0 = min.0.min(Self::SAND_SOURCE.0 - floor_y);
+            max.0 = max.0.max(Self::SAND_SOURCE.0 + floor_y + 1);
+            max.1 = floor_y + 1;
+        }
+
+        let size = max - min;
+        let mut map = Map2D::new_filled(size, Tile::Air);
+
+        for point in lines.iter().flat_map(Line::points).map(|p| p - min) {
+            map.set_tile(point, Tile::Rock);
+        }
+        if floor {
+            for x in 0..size.0 {
+                map.set_tile(Point2D(x, floor_y), Tile::Rock);
+            }
+        }
+
+        let sand_source = Self::SAND_SOURCE - min;
+        map.set_tile(sand_source, Tile::Source);
+
+        Ok(Self { map, sand_source, recorder: None })
+    }
+
+    /// Drops a unit of sand, returns false if it fell out of the map or the source is already covered.
+    pub fn spawn_sand(&mut self) -> bool {
+        if self.try_collision(self.sand_source) == CollisionResult::Collision {
+            return false;
+        }
+
+        const CHECK_POINTS: [Point2D; 3] = [Point2D(0, 1), Point2D(-1, 1), Point2D(1, 1)];
+        let mut position = self.sand_source;
+
+        loop {
+            let mut did_move = false;
+            for check_point in CHECK_POINTS.map(|p| position + p) {
+                match self.try_collision(check_point) {
+                    CollisionResult::Collision => continue,
+                    CollisionResult::Air => {
+                        position = check_point;
+                        did_move = true;
+                        break;
+                    },
+                    CollisionResult::Outside => return false,
+                }
+            }
+
+            if !did_move {
+                self.map.set_tile(position, Tile::Sand);
+                if let Some(recorder) = &mut self.recorder {
+                    recorder.step_map(&self.map, &Self::palette());
+                }
+                return true;
+            }
+        }
+    }
+
+    fn try_collision(&self, at: Point2D) -> CollisionResult {
+        match self.map.get_tile(at) {
+            Some(Tile::Rock | Tile::Sand) => CollisionResult::Collision,
+            Some(Tile::Air | Tile::Source) => CollisionResult::Air,
+            None => CollisionResult::Outside,
+        }
+    }
+
+    pub fn fill_with_sand(&mut self) -> usize {
+        let mut result = 0;
+        while self.spawn_sand() {
+            result += 1;
+        }
+        result
+    }
+
+    fn palette() -> Palette {
+        Palette::new(BLACK)
+            .with('#', [128, 128, 128])
+            .with('O', [230, 200, 100])
+            .with('+', RED)
+    }
+}
+
+impl Simulation for CaveMap {
+    /// A unit of sand at a time.
+    fn step(&mut self) -> bool {
+        self.spawn_sand()
+    }
+
+    fn render(&self) -> Map2D<char> {
+        self.map.map_tiles(|&tile| tile.into())
+    }
+}
+
+/// The sand filling the cave of part 2, for the visualizer.
+pub fn simulation(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let lines = Day::parse(input)?;
+    Ok(Box::new(CaveMap::try_new(&lines, true)?))
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part_1(lines: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut map = CaveMap::try_new(lines, false)?;
+        map.recorder = Recorder::if_enabled("day_14_part_1");
+        let result = map.fill_with_sand();
+
+        if let Some(recorder) = map.recorder.take() {
+            recorder.finish()?;
+        }
+        Ok(result)
+    }
+
+    fn part_2(lines: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut map = CaveMap::try_new(lines, true)?;
+        map.recorder = Recorder::if_enabled("day_14_part_2");
+        let result = map.fill_with_sand();
+
+        if let Some(recorder) = map.recorder.take() {
+            recorder.finish()?;
+        }
+        Ok(result)
+    }
+}
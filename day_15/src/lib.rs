@@ -0,0 +1,147 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use anyhow::{self, Context};
+use xmas::{interval::IntervalSet, parse::{self, Line, ParseError}, point2d::Point2D, solver::{Params, Solver}};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sensor {
+    position: Point2D,
+    closest_beacon: Point2D,
+}
+
+impl Sensor {
+    fn distance_to_beacon(&self) -> usize {
+        self.position.manhattan_distance(self.closest_beacon)
+    }
+
+    fn get_range_at_row(&self, y: isize) -> Option<RangeInclusive<isize>> {
+        let distance = self.distance_to_beacon();
+        let y_diff = y.abs_diff(self.position.1);
+        if y_diff > distance {
+            return None;
+        }
+
+        let extend = distance - y_diff;
+        // println!("Row {y}: Point {} extends {extend} (Distance: {})", self.position, self.distance_to_beacon());
+        Some((self.position.0 - extend as isize)..=(self.position.0 + extend as isize))
+    }
+}
+
+impl Sensor {
+    pub fn from_line(line: &Line<'_>) -> Result<Self, ParseError> {
+        let [x, y, beacon_x, beacon_y] = line.ints()?[..] else {
+            return Err(line.error("Expected the sensor and beacon coordinates"));
+        };
+        Ok(Self {
+            position: Point2D(x, y),
+            closest_beacon: Point2D(beacon_x, beacon_y),
+        })
+    }
+}
+
+/// The sensors with the row to check and the size of the space to search, which the puzzle gives
+/// in its text and the example scales down.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Survey {
+    sensors: Vec<Sensor>,
+    check_row: isize,
+    search_range: RangeInclusive<isize>,
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Survey;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    /// Takes the `row` to check in part 1 and the `search_max` coordinate of part 2 from `params`.
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        let sensors = parse::lines(input).map(|line| Sensor::from_line(&line)).collect::<Result<_, _>>()?;
+        Ok(Survey {
+            sensors,
+            check_row: params.get_or("row", 2_000_000) as isize,
+            search_range: 0..=params.get_or("search_max", 4_000_000) as isize,
+        })
+    }
+
+    fn part_1(survey: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        Ok(get_non_beacon_count(&survey.sensors, survey.check_row))
+    }
+
+    fn part_2(survey: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let space = find_beacon_space(&survey.sensors, survey.search_range.clone())
+            .context("No beacon space found")?;
+
+        let result = space.0 * 4000000 + space.1;
+
+        Ok(result)
+    }
+}
+
+fn get_non_beacon_count(sensors: &[Sensor], row: isize) -> usize {
+    let covered = get_ranges_at_row(sensors, row);
+    let beacons_in_row = sensors.iter()
+        .map(|s| s.closest_beacon)
+        .filter(|b| b.1 == row)
+        .map(|b| b.0)
+        .collect::<HashSet<_>>();
+
+    covered.len() - beacons_in_row.len()
+}
+
+fn get_ranges_at_row(sensors: &[Sensor], row: isize) -> IntervalSet<isize> {
+    sensors.iter().flat_map(|s| s.get_range_at_row(row)).collect()
+}
+
+fn find_beacon_space(sensors: &[Sensor], range: RangeInclusive<isize>) -> Option<Point2D> {
+    range.clone().find_map(|row| {
+        get_ranges_at_row(sensors, row)
+            .first_gap(range.clone())
+            .map(|x| Point2D(x, row))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n",
+        "Sensor at x=9, y=16: closest beacon is at x=10, y=16\n",
+        "Sensor at x=13, y=2: closest beacon is at x=15, y=3\n",
+        "Sensor at x=12, y=14: closest beacon is at x=10, y=16\n",
+        "Sensor at x=10, y=20: closest beacon is at x=10, y=16\n",
+        "Sensor at x=14, y=17: closest beacon is at x=10, y=16\n",
+        "Sensor at x=8, y=7: closest beacon is at x=2, y=10\n",
+        "Sensor at x=2, y=0: closest beacon is at x=2, y=10\n",
+        "Sensor at x=0, y=11: closest beacon is at x=2, y=10\n",
+        "Sensor at x=20, y=14: closest beacon is at x=25, y=17\n",
+        "Sensor at x=17, y=20: closest beacon is at x=21, y=22\n",
+        "Sensor at x=16, y=7: closest beacon is at x=15, y=3\n",
+        "Sensor at x=14, y=3: closest beacon is at x=15, y=3\n",
+        "Sensor at x=20, y=1: closest beacon is at x=15, y=3\n",
+    );
+
+    fn example_params() -> Params {
+        Params::new().with("row", 10).with("search_max", 20)
+    }
+
+    #[test]
+    fn counts_example_row() {
+        let survey = Day::parse_with(EXAMPLE, &example_params()).unwrap();
+        assert_eq!(survey.check_row, 10);
+        assert_eq!(get_non_beacon_count(&survey.sensors, 10), 26);
+    }
+
+    #[test]
+    fn finds_example_beacon_space() {
+        let survey = Day::parse_with(EXAMPLE, &example_params()).unwrap();
+        assert_eq!(survey.search_range, 0..=20);
+        assert_eq!(find_beacon_space(&survey.sensors, 0..=20), Some(Point2D(14, 11)));
+    }
+}
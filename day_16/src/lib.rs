@@ -0,0 +1,216 @@
+use std::{cmp::Reverse, collections::HashMap, str::FromStr};
+
+use anyhow::{self, Context};
+use regex_static::{lazy_regex, Regex, once_cell::sync::Lazy};
+use xmas::{parse, search::Search, solver::Solver};
+
+static VALVE_REGEX: Lazy<Regex> = lazy_regex!(r"Valve (\S+) .*rate=(\d+).*valves?(.*)");
+
+type ValveId = String;
+const START_ID: &str = "AA";
+
+#[derive(Debug, Clone)]
+pub struct Valve {
+    id: ValveId,
+    rate: usize,
+    leads_to: Vec<ValveId>,
+    path_costs: HashMap<ValveId, usize>,
+}
+
+impl FromStr for Valve {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re_match = VALVE_REGEX.captures(s)
+            .with_context(|| format!("Invalid valve info format: {}", s))?;
+        let id = re_match.get(1).unwrap().as_str().to_string();
+        let rate = re_match.get(2).unwrap().as_str().parse::<usize>()?;
+        let leads_to = re_match
+            .get(3).unwrap().as_str()
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .collect::<Vec<_>>();
+        Ok(Valve { id, rate, leads_to, path_costs: Default::default() })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValveSystem {
+    valves: HashMap<ValveId, Valve>,
+    valve_bits: HashMap<ValveId, u64>,
+}
+
+impl ValveSystem {
+    pub fn try_new(valves: impl Iterator<Item = Valve>) -> Result<Self, anyhow::Error> {
+        let valves_map: HashMap<String, Valve> = valves
+            .map(|v| (v.id.clone(), v))
+            .collect();
+        if !valves_map.contains_key(START_ID) {
+            Err(anyhow::anyhow!("No valve with id {} found", START_ID))?;
+        }
+
+        let mut openable = valves_map.values()
+            .filter(|v| v.rate > 0)
+            .map(|v| v.id.clone())
+            .collect::<Vec<_>>();
+        openable.sort();
+        if openable.len() > u64::BITS as usize {
+            Err(anyhow::anyhow!("Too many valves with a flow rate: {}", openable.len()))?;
+        }
+        let valve_bits = openable.into_iter()
+            .enumerate()
+            .map(|(i, id)| (id, 1 << i))
+            .collect();
+
+        let mut system = ValveSystem {
+            valves: valves_map,
+            valve_bits,
+        };
+        system.recalculate_paths();
+        // println!("Created valve system:");
+        // println!("{:#?}", system);
+        Ok(system)
+    }
+
+    fn recalculate_paths(&mut self) {
+        let mut costs = Vec::new();
+        let valid_targets = self.valves
+            .values()
+            .filter(|v| v.rate > 0)
+            .map(|v| v.id.as_str())
+            .collect::<Vec<_>>();
+        for from in self.valves.values() {
+            let path_costs: HashMap<_, _> = valid_targets
+                .iter()
+                .map(|to| self.calculate_path_cost(&from.id, to).map(|cost| (to.to_string(), cost)).unwrap())
+                .collect();
+            costs.push((from.id.clone(), path_costs));
+        }
+
+        for (from, path_costs) in costs {
+            let valve = self.valves.get_mut(&from).unwrap();
+            valve.path_costs = path_costs;
+        }
+    }
+
+    fn calculate_path_cost(&self, from: &str, to: &str) -> Option<usize> {
+        // println!("Calculating path {from} -> {to}");
+        let found = Search::by_node().bfs(
+            from,
+            |&id| self.valves[id].leads_to.iter().map(String::as_str),
+            |&id| id == to,
+        ).ok()?;
+        Some(found.cost)
+    }
+
+    /// Every state reachable by opening valves within `time_limit`, best final pressure first.
+    fn valve_states(&self, time_limit: usize) -> Vec<ValveState<'_>> {
+        let start = ValveState {
+            at: START_ID,
+            opened: 0,
+            time_left: time_limit,
+            final_pressure: 0,
+        };
+        Search::by_node()
+            .best_first(start, |state| self.find_candidates(state), |state| state.final_pressure)
+            .collect()
+    }
+
+    pub fn calculate_greatest_amount_of_pressure(&self, time_limit: usize) -> usize {
+        self.valve_states(time_limit)
+            .into_iter()
+            .map(|state| state.final_pressure)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn find_candidates<'a>(&'a self, cur: &ValveState<'a>) -> impl Iterator<Item = ValveState<'a>> + 'a {
+        let ValveState { at, opened, time_left, final_pressure } = *cur;
+
+        self.valves[at].path_costs
+            .iter()
+            .filter(move |(_, cost)| (*cost + 1) < time_left)
+            .filter(move |(id, _)| opened & self.valve_bits[*id] == 0)
+            .map(move |(id, cost)| {
+                // println!("{} -> {} will cost {} for time left {}", at, id, cost, time_left);
+                let target_valve = &self.valves[id];
+                let new_time_left = time_left - (cost + 1);
+                ValveState {
+                    at: &target_valve.id,
+                    opened: opened | self.valve_bits[id],
+                    time_left: new_time_left,
+                    final_pressure: final_pressure + target_valve.rate * new_time_left,
+                }
+            })
+    }
+
+    pub fn calculate_greatest_pressure_with_elephant(&self, time_limit: usize) -> usize {
+        // Only the best pressure for each set of opened valves can be part of the best pair
+        let mut best_by_opened = HashMap::new();
+        for state in self.valve_states(time_limit) {
+            let best = best_by_opened.entry(state.opened).or_insert(0);
+            *best = state.final_pressure.max(*best);
+        }
+        // println!("Testing combinations of {} valve set/s", best_by_opened.len());
+
+        let mut final_sets = best_by_opened.into_iter().collect::<Vec<_>>();
+        final_sets.sort_by_key(|&(_, pressure)| Reverse(pressure));
+
+        let mut max_pressure = 0;
+        for (i, &(user_opened, user_pressure)) in final_sets.iter().enumerate() {
+            for &(elephant_opened, elephant_pressure) in &final_sets[i..] {
+                // Sorted by pressure, so no later pair can beat the best one anymore
+                if user_pressure + elephant_pressure <= max_pressure {
+                    break;
+                }
+                if user_opened & elephant_opened == 0 {
+                    max_pressure = user_pressure + elephant_pressure;
+                    // println!("Found candidate pressure: {} ({} + {})", max_pressure, user_pressure, elephant_pressure);
+                    break;
+                }
+            }
+        }
+        max_pressure
+    }
+}
+
+impl FromStr for ValveSystem {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valves: Vec<Valve> = parse::parse_lines(s)?;
+
+        Self::try_new(valves.into_iter())
+    }
+}
+
+/// Where we are after opening the valves in `opened`, a bit per valve with a non zero rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValveState<'a> {
+    at: &'a str,
+    opened: u64,
+    time_left: usize,
+    final_pressure: usize,
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = ValveSystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        ValveSystem::from_str(input)
+    }
+
+    fn part_1(system: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result = system.calculate_greatest_amount_of_pressure(30);
+        Ok(result)
+    }
+
+    fn part_2(system: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let result = system.calculate_greatest_pressure_with_elephant(26);
+        Ok(result)
+    }
+}
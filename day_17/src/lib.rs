@@ -0,0 +1,343 @@
+use std::{cmp::max, collections::HashSet, str::FromStr};
+
+use anyhow::{self, Context};
+use xmas::{cycle::find_cycle, image::{Image, BLACK, WHITE}, map2d::Map2D, point2d::Point2D, record::Recorder, solver::Solver, visualize::Simulation};
+
+#[derive(Debug, Clone)]
+struct RockShape {
+    /// Top row first, like the shapes are drawn.
+    tiles: Map2D<bool>,
+}
+
+impl RockShape {
+    pub fn default_shapes() -> Vec<RockShape> {
+        const PATTERNS: [&str; 5] = [
+            "####",
+            ".#.\n###\n.#.",
+            "..#\n..#\n###",
+            "#\n#\n#\n#",
+            "##\n##",
+        ];
+
+        PATTERNS.into_iter()
+            .map(Self::from_str)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    pub fn width(&self) -> usize {
+        self.tiles.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.tiles.height()
+    }
+
+    /// Points of the rock relative to its top left corner, with y going up.
+    pub fn local_points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        self.tiles.iter_with_points()
+            .filter(|&(_, &rock)| rock)
+            .map(|(Point2D(x, y), _)| Point2D(x, -y))
+    }
+}
+
+impl FromStr for RockShape {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Map2D::<u8>::from_str(s)?.map_tiles(|&tile| tile == b'#');
+        Ok(Self { tiles })
+    }
+}
+
+struct RockInstance<'a> {
+    shape: &'a RockShape,
+    position: Point2D,
+}
+
+impl<'a> RockInstance<'a> {
+    pub fn move_towards(&self, direction: Point2D) -> Self {
+        Self {
+            position: self.position + direction,
+            shape: self.shape,
+        }
+    }
+
+    pub fn upper_bound(&self) -> isize {
+        self.position.1
+    }
+
+    pub fn lower_bound(&self) -> isize {
+        self.position.1 - self.shape.height() as isize + 1
+    }
+
+    pub fn left_bound(&self) -> isize {
+        self.position.0
+    }
+
+    pub fn right_bound(&self) -> isize {
+        self.position.0 + self.shape.width() as isize - 1
+    }
+
+    pub fn check_collision(&self, map: &RockFormation) -> bool {
+        if self.lower_bound() <= 0 ||
+            self.left_bound() <= 0 ||
+            self.right_bound() >= map.right_wall()
+        {
+            return true;
+        }
+
+        self.world_points()
+            .any(|p| map.tiles.contains(&p))
+    }
+
+    pub fn local_points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        self.shape.local_points()
+    }
+
+    pub fn world_points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        self.local_points()
+            .map(|p| p + self.position)
+    }
+}
+
+impl<'a> Clone for RockInstance<'a> {
+    fn clone(&self) -> Self {
+        Self { shape: self.shape, position: self.position }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum StreamDirection {
+    Left,
+    Right,
+}
+
+impl StreamDirection {
+    pub fn pattern_from_str(s: &str) -> Result<Vec<Self>, anyhow::Error> {
+        s.trim().bytes()
+            .map(|b| match b {
+                b'<' => Ok(Self::Left),
+                b'>' => Ok(Self::Right),
+                _ => Err(anyhow::anyhow!("Invalid direction: {:?}", char::from_u32(b as u32))),
+            })
+            .collect()
+    }
+
+    pub fn as_movement(&self) -> Point2D {
+        match self {
+            Self::Left => Point2D(-1, 0),
+            Self::Right => Point2D(1, 0),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RockFormation {
+    tiles: HashSet<Point2D>,
+    height: usize,
+    /// Height of the highest rock in each column, left to right.
+    column_heights: Vec<usize>,
+
+    shapes: Vec<RockShape>,
+    use_shape: usize,
+
+    stream_pattern: Vec<StreamDirection>,
+    use_stream: usize,
+
+    /// Gets a frame of the top of the tower after each rock.
+    recorder: Option<Recorder>,
+}
+
+impl RockFormation {
+    fn new(stream_pattern: Vec<StreamDirection>) -> Self {
+        let shapes = RockShape::default_shapes();
+        Self {
+            tiles: Default::default(),
+            height: 0,
+            column_heights: vec![0; 7],
+            shapes,
+            use_shape: 0,
+            stream_pattern,
+            use_stream: 0,
+            recorder: None,
+        }
+    }
+
+    fn throw_many_rocks(&mut self, amount: usize) {
+        for _ in 0..amount {
+            self.throw_rock();
+        }
+    }
+
+    fn throw_rock(&mut self) {
+        const LEFT_MARGIN: isize = 3;
+        const LOWER_MARGIN: isize = 3;
+
+        let shape = &self.shapes[self.use_shape];
+        let position = Point2D(LEFT_MARGIN, self.height as isize + LOWER_MARGIN + shape.height() as isize);
+        let mut rock = RockInstance { shape, position };
+        
+        // println!("Spawned rock[{}] @ {}", self.use_shape, position);
+        loop {
+            let direction = self.stream_pattern[self.use_stream];
+            self.use_stream = (self.use_stream + 1) % self.stream_pattern.len();
+
+            // let dir = match direction {
+            //     StreamDirection::Left => ">",
+            //     StreamDirection::Right => "<",
+            // };
+            // print!("Moved rock towards {dir}, result: ");
+
+            let stream_moved_rock = rock.move_towards(direction.as_movement());
+            if !stream_moved_rock.check_collision(self) {
+                rock = stream_moved_rock;
+                // println!("Moved")
+            } else {
+                // println!("Blocked")
+            }
+
+            let gravity_moved_rock = rock.move_towards(Point2D(0, -1));
+            if gravity_moved_rock.check_collision(self) {
+                // println!("Ground hit!");
+                // if use_stream_at_start == 0 && self.use_shape == 0 {
+                //     println!("Placed rock[0] @ {}", rock.position);
+                // }
+                break;
+            } else {
+                rock = gravity_moved_rock;
+                // println!("Rock falls 1 unit");
+            }
+        }
+
+        self.height = max(self.height, rock.upper_bound() as usize);
+        for Point2D(x, y) in rock.world_points() {
+            let column_height = &mut self.column_heights[x as usize - 1];
+            *column_height = max(*column_height, y as usize);
+        }
+        self.tiles.extend(rock.world_points());
+        self.use_shape = (self.use_shape + 1) % self.shapes.len();
+
+        if let Some(mut recorder) = self.recorder.take() {
+            recorder.step(|| self.top_image());
+            self.recorder = Some(recorder);
+        }
+    }
+
+    /// Image of the top rows of the tower, walls and floor included.
+    fn top_image(&self) -> Image {
+        const ROWS: isize = 40;
+        let top = self.height as isize;
+        let mut image = Image::new(Point2D(self.right_wall() + 1, ROWS), BLACK);
+        for row in 0..ROWS {
+            let y = top - row;
+            for x in 0..=self.right_wall() {
+                let is_wall = x == 0 || x == self.right_wall() || y <= 0;
+                if is_wall {
+                    image.set(Point2D(x, row), [128, 128, 128]);
+                } else if self.tiles.contains(&Point2D(x, y)) {
+                    image.set(Point2D(x, row), WHITE);
+                }
+            }
+        }
+        image
+    }
+
+    /// Everything the next rocks depend on: which shape and stream come next and how deep each
+    /// column is from the top.
+    fn cycle_key(&self) -> (usize, usize, Vec<usize>) {
+        let surface = self.column_heights.iter()
+            .map(|h| self.height - h)
+            .collect();
+        (self.use_shape, self.use_stream, surface)
+    }
+
+    pub fn right_wall(&self) -> isize { 
+        8 // Hardcoded
+    }
+}
+
+impl Simulation for RockFormation {
+    /// A rock at a time, there's always another one.
+    fn step(&mut self) -> bool {
+        self.throw_rock();
+        true
+    }
+
+    /// The whole tower with the highest rows first, leaving room above it for the next rock.
+    fn render(&self) -> Map2D<char> {
+        const HEADROOM: isize = 7;
+        let top = self.height as isize + HEADROOM;
+        let mut map = Map2D::new_filled(Point2D(self.right_wall() + 1, top + 1), '.');
+        for row in 0..=top {
+            let y = top - row;
+            for x in 0..=self.right_wall() {
+                let tile = match (x == 0 || x == self.right_wall(), y == 0) {
+                    (true, true) => '+',
+                    (true, false) => '|',
+                    (false, true) => '-',
+                    (false, false) if self.tiles.contains(&Point2D(x, y)) => '#',
+                    _ => continue,
+                };
+                map.set_tile(Point2D(x, row), tile);
+            }
+        }
+        map
+    }
+}
+
+/// Rocks falling one by one, for the visualizer.
+pub fn simulation(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    Ok(Box::new(RockFormation::new(Day::parse(input)?)))
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<StreamDirection>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        StreamDirection::pattern_from_str(input)
+    }
+
+    fn part_1(pattern: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut formation = RockFormation::new(pattern.clone());
+        formation.recorder = Recorder::if_enabled("day_17_part_1");
+
+        formation.throw_many_rocks(2022);
+        let result = formation.height;
+
+        if let Some(recorder) = formation.recorder.take() {
+            recorder.finish()?;
+        }
+
+        Ok(result)
+    }
+
+    fn part_2(pattern: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let formation = RockFormation::new(pattern.clone());
+
+        // A round throws a rock for every pairing of jet and shape, a cycle takes at least one to
+        // line them up again and a few more for the surface to settle. The example repeats within
+        // its first round, past this many the surface is taken to never repeat.
+        const MAX_ROUNDS: usize = 10;
+        let max_rocks = formation.stream_pattern.len() * formation.shapes.len() * MAX_ROUNDS;
+        let cycle = find_cycle(
+            formation,
+            max_rocks,
+            RockFormation::throw_rock,
+            RockFormation::cycle_key,
+            |f| f.height,
+        ).with_context(|| format!("No repeating surface found within {max_rocks} rocks ({MAX_ROUNDS} rounds of jets and shapes)"))?;
+
+        const ROCKS: usize = 1_000_000_000_000;
+        let result = cycle.extrapolate(ROCKS).context("Height doesn't fit")?;
+
+        // 1514285714288
+        // 714285714300
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use xmas::{direction3d::DIRECTIONS_3D, parse, point3d::Point3D, region, solver::Solver};
+
+#[derive(Debug, Clone)]
+struct Bounds {
+    min: Point3D,
+    max: Point3D,
+}
+
+impl Bounds {
+    /// Box leaving a gap of one around `points`.
+    pub fn around(points: &[Point3D]) -> Option<Self> {
+        let min = points.iter().copied().reduce(|p1, p2| p1.min(p2))?;
+        let max = points.iter().copied().reduce(|p1, p2| p1.max(p2))?;
+        Some(Self { min: min - Point3D::splat(1), max: max + Point3D::splat(1) })
+    }
+
+    pub fn is_inside(&self, point: Point3D) -> bool {
+        point.0 >= self.min.0 && point.1 >= self.min.1 && point.2 >= self.min.2 &&
+        point.0 <= self.max.0 && point.1 <= self.max.1 && point.2 <= self.max.2
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<Point3D>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part_1(points: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result = calculate_surface_area(points);
+        Ok(result)
+    }
+
+    fn part_2(points: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let result = calculate_exterior_surface_area(points);
+        Ok(result)
+    }
+}
+
+fn calculate_surface_area(points: &[Point3D]) -> usize {
+    let mut already_placed = HashSet::new();
+    let mut area = 0;
+    for &point in points.iter() {
+        area += DIRECTIONS_3D.len();
+        area -= DIRECTIONS_3D.iter()
+            .map(|d| point + d.as_point())
+            .filter(|p| already_placed.contains(p))
+            .count() * 2;
+        already_placed.insert(point);
+    }
+    area
+}
+
+/// Faces of the droplet touched by the air around it, which is filled in from a corner of a box
+/// one cube bigger than the droplet on every side.
+fn calculate_exterior_surface_area(points: &[Point3D]) -> usize {
+    let Some(bounds) = Bounds::around(points) else {
+        return 0;
+    };
+    let lava = points.iter().copied().collect::<HashSet<_>>();
+
+    let outside = region::flood_fill_3d(bounds.min, |p| bounds.is_inside(p) && !lava.contains(&p));
+    outside.into_iter()
+        .flat_map(region::neighbors_3d)
+        .filter(|p| lava.contains(p))
+        .count()
+}
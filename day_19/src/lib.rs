@@ -0,0 +1,284 @@
+use anyhow::{self, Context};
+use enum_map::{Enum, EnumMap};
+use xmas::{parse::{self, Line, ParseError}, search::Search, solver::Solver};
+use rayon::prelude::*;
+use ResourceType::*;
+
+type Minutes = u64;
+type ResourceList = EnumMap<ResourceType, u64>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum ResourceType {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    number: u64,
+    ore_robot_ore_cost: u64,
+    clay_robot_ore_cost: u64,
+    obs_robot_ore_clay_cost: (u64, u64),
+    geode_robot_ore_obsidian_cost: (u64, u64),
+    max_costs: ResourceList,
+}
+
+impl Blueprint {
+    pub fn get_max_geodes_path(&self, initial_state: State) -> u64 {
+        // Which robot was created last doesn't change what can happen next
+        let search = Search::keyed_by(|s: &State| (s.time_left, s.ore, s.clay, s.obsidian, s.geodes));
+        search.best_first(initial_state, |s| self.next_states(s), State::final_geodes)
+            .map(|s| s.final_geodes())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn next_states(&self, candidate: &State) -> impl Iterator<Item = State> {
+        // We could simplify this list by creating a ResourceList struct
+        // and set the consumption and increase rates
+
+        let create_ore_bot = candidate.ore
+            .time_to_get(self.ore_robot_ore_cost)
+            .filter(|_| candidate.could_create_more(Ore, self))
+            .and_then(|time| candidate.after(time + 1))
+            .map(|s| State {
+                ore: s.ore.consume(self.ore_robot_ore_cost).add_generation(1),
+                created_robot: Ore,
+                ..s
+            });
+
+        let create_clay_robot = candidate.ore
+            .time_to_get(self.clay_robot_ore_cost)
+            .filter(|_| candidate.could_create_more(Clay, self))
+            .and_then(|time| candidate.after(time + 1))
+            .map(|s| State {
+                ore: s.ore.consume(self.clay_robot_ore_cost),
+                clay: s.clay.add_generation(1),
+                created_robot: Clay,
+                ..s
+            });
+
+        let create_obsidian_robot = candidate.ore
+            .time_to_get(self.obs_robot_ore_clay_cost.0)
+            .filter(|_| candidate.could_create_more(Obsidian, self))
+            .and_then(|t1| candidate.clay.time_to_get(self.obs_robot_ore_clay_cost.1)
+                .map(|t2| t1.max(t2)))
+            .and_then(|time| candidate.after(time + 1))
+            .map(|s| State {
+                ore: s.ore.consume(self.obs_robot_ore_clay_cost.0),
+                clay: s.clay.consume(self.obs_robot_ore_clay_cost.1),
+                obsidian: s.obsidian.add_generation(1),
+                created_robot: Obsidian,
+                ..s
+            });
+
+        let create_geode_robot = candidate.ore
+            .time_to_get(self.geode_robot_ore_obsidian_cost.0)
+            .and_then(|t1| candidate.obsidian.time_to_get(self.geode_robot_ore_obsidian_cost.1)
+                .map(|t2| t1.max(t2)))
+            .and_then(|time| candidate.after(time + 1))
+            .map(|s| State {
+                ore: s.ore.consume(self.geode_robot_ore_obsidian_cost.0),
+                obsidian: s.obsidian.consume(self.geode_robot_ore_obsidian_cost.1),
+                geodes: s.geodes.add_generation(1),
+                created_robot: Geode,
+                ..s
+            });
+
+        create_ore_bot.into_iter()
+            .chain(create_clay_robot)
+            .chain(create_obsidian_robot)
+            .chain(create_geode_robot)
+    }
+}
+
+impl Blueprint {
+    pub fn from_line(line: &Line<'_>) -> Result<Self, ParseError> {
+        let [number, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = line.ints()?[..] else {
+            return Err(line.error("Expected a blueprint number and 6 robot costs"));
+        };
+
+        let mut costs = EnumMap::<ResourceType, ResourceList>::default();
+        costs[Ore][Ore] = ore_ore;
+        costs[Clay][Ore] = clay_ore;
+        costs[Obsidian][Ore] = obsidian_ore;
+        costs[Obsidian][Clay] = obsidian_clay;
+        costs[Geode][Ore] = geode_ore;
+        costs[Geode][Obsidian] = geode_obsidian;
+        // println!("costs: {:#?}", costs);
+
+        let max_costs = costs.values()
+            .cloned()
+            .reduce(|a, b| a.into_iter().map(|(k, v)| (k, v.max(b[k]))).collect::<ResourceList>())
+            .unwrap_or_default();
+        // println!("max_costs: {:?}", max_costs);
+
+        Ok(Self {
+            number,
+            ore_robot_ore_cost: costs[Ore][Ore],
+            clay_robot_ore_cost: costs[Clay][Ore],
+            obs_robot_ore_clay_cost: (costs[Obsidian][Ore], costs[Obsidian][Clay]),
+            geode_robot_ore_obsidian_cost: (costs[Geode][Ore], costs[Geode][Obsidian]),
+            max_costs,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    time_left: u64,
+    // stock: ResourceList,
+    // generating: ResourceList,
+    ore: Resource,
+    clay: Resource,
+    obsidian: Resource,
+    geodes: Resource,
+    created_robot: ResourceType,
+}
+
+impl State {
+    pub fn initial_state(time_left: u64) -> Self {
+        // let mut generating = ResourceList::default();
+        // generating[ResourceType::Ore] = 1;
+        Self {
+            time_left,
+            ore: Resource { amount: 0, generation_per_minute: 1 },
+            clay: Resource { amount: 0, generation_per_minute: 0 },
+            obsidian: Resource { amount: 0, generation_per_minute: 0 },
+            geodes: Resource { amount: 0, generation_per_minute: 0 },
+            created_robot: ResourceType::Ore,
+            // generating,
+            // stock: Default::default(),
+        }
+    }
+
+    pub fn after(&self, time: Minutes) -> Option<Self> {
+        if time > self.time_left {
+            return None;
+        }
+
+        Some(Self {
+            time_left: self.time_left - time,
+            ore: self.ore.after(time),
+            clay: self.clay.after(time),
+            obsidian: self.obsidian.after(time),
+            geodes: self.geodes.after(time),
+            created_robot: self.created_robot,
+        })
+    }
+
+    pub fn final_geodes(&self) -> u64 {
+        self.geodes.after(self.time_left).amount
+    }
+
+    pub fn could_create_more(&self, res: ResourceType, bp: &Blueprint) -> bool {
+        let amount = self.amount_of(res);
+        amount <= bp.max_costs[res] + 1
+    }
+
+    pub fn amount_of(&self, res: ResourceType) -> u64 {
+        match res {
+            Ore => self.ore.amount,
+            Clay => self.clay.amount,
+            Obsidian => self.obsidian.amount,
+            Geode => self.geodes.amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Resource {
+    amount: u64,
+    generation_per_minute: Minutes,
+}
+
+impl Resource {
+    pub fn time_to_get(&self, amount: u64) -> Option<Minutes> {
+        // println!("Wanting to get {}, currently have {} @ {} / minute", amount, self.amount, self.generation_per_minute);
+        if self.amount >= amount {
+            return Some(0);
+        }
+
+        if self.generation_per_minute == 0 {
+            return None;
+        }
+
+        let needed = amount - self.amount;
+        Some(needed.div_ceil(self.generation_per_minute))
+    }
+
+    pub fn after(&self, time: Minutes) -> Self {
+        Self {
+            amount: self.amount + (self.generation_per_minute * time),
+            generation_per_minute: self.generation_per_minute,
+        }
+    }
+
+    pub fn consume(&self, amount: u64) -> Self {
+        Self {
+            amount: self.amount - amount,
+            generation_per_minute: self.generation_per_minute,
+        }
+    }
+
+    pub fn add_generation(&self, amount: u64) -> Self {
+        Self {
+            amount: self.amount,
+            generation_per_minute: self.generation_per_minute + amount,
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<Blueprint>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::lines(input).map(|line| Blueprint::from_line(&line)).collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(blueprints: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        // println!("{:?}", blueprints);
+
+        const TIME: Minutes = 24;
+        let results = blueprints.par_iter()
+            .map(|bp| (bp.number, bp.get_max_geodes_path(State::initial_state(TIME))))
+            .collect::<Vec<_>>();
+
+        // for (n, geodes) in results.iter() {
+        //     println!("Blueprint {}: {} geode/s = {} quality", n, geodes, n * geodes);
+        // }
+
+        let result = results
+            .into_iter()
+            .map(|(n, geodes)| n * geodes)
+            .sum::<u64>();
+
+        Ok(result)
+    }
+
+    fn part_2(blueprints: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        const TIME: Minutes = 32;
+        let results = blueprints.par_iter()
+            .take(3)
+            .map(|bp| (bp.number, bp.get_max_geodes_path(State::initial_state(TIME))))
+            .collect::<Vec<_>>();
+
+        // for (n, geodes) in results.iter() {
+        //     println!("Blueprint {}: {} geode/s", n, geodes);
+        // }
+
+        let result = results
+            .into_iter()
+            .map(|(_, geodes)| geodes)
+            .reduce(|a, b| a * b)
+            .context("No blueprints found")?;
+
+        Ok(result)
+    }
+}
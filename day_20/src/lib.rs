@@ -0,0 +1,51 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use anyhow::{self, Context};
+use xmas::solver::Solver;
+use mixer::ShiftMixer;
+
+mod mixer;
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_numbers(input)?)
+    }
+
+    fn part_1(numbers: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut mixer = ShiftMixer::new(numbers);
+        mixer.mix()?;
+
+        let mixed_numbers: Vec<i64> = mixer.iter().collect();
+        grove_coordinates_sum(&mixed_numbers)
+    }
+
+    fn part_2(numbers: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut mixer = ShiftMixer::new_with_key(numbers, 811589153);
+        mixer.mix_many(10)?;
+
+        let mixed_numbers: Vec<i64> = mixer.iter().collect();
+        grove_coordinates_sum(&mixed_numbers)
+    }
+}
+
+fn grove_coordinates_sum(mixed_numbers: &[i64]) -> anyhow::Result<i64> {
+    // println!("Mixed numbers: {:?}", mixed_numbers);
+    let zero_i = mixed_numbers.iter().position(|n| *n == 0).context("No 0 found")?;
+    let result = [1000, 2000, 3000]
+        .into_iter()
+        .map(|i| mixed_numbers[(zero_i + i) % mixed_numbers.len()])
+        .sum::<i64>();
+    Ok(result)
+}
+
+fn parse_numbers(content: &str) -> Result<Vec<i64>, ParseIntError> {
+    content.lines()
+        .map(i64::from_str)
+        .collect::<Result<Vec<_>, _>>()
+}
@@ -137,10 +137,10 @@ impl<'a> ShiftMixer<'a> {
 
     pub fn mix_many(&mut self, times: usize) -> Result<(), anyhow::Error> {
         let zero_i = self.original.iter().position(|&n| n == 0).unwrap();
-        for i in 1..=times {
+        for _ in 1..=times {
             self.mix()?;
             self.start = zero_i;
-            println!("Mixed {}/{}", i, times);
+            // println!("Mixed {}/{}", i, times);
         }
         Ok(())
     }
@@ -1,39 +1,32 @@
 use std::{collections::HashMap, str::FromStr};
 
 use anyhow::{self, Context};
-use xmas::display_result;
+use xmas::{parse::{self, Line, ParseError}, solver::Solver};
 use self::MonkeyResult::*;
 
 const ROOT: &str = "root";
 const HUMAN: &str = "humn";
 
-fn main() -> anyhow::Result<()> {
-    part_1()?;
-    println!();
-    part_2()?;
-    Ok(())
-}
-
-fn part_1() -> anyhow::Result<()> {
-    println!("Part 1:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
+pub struct Day;
 
-    let monkeys = MonkeyGroup::from_str(&input)?;
-    let result = monkeys.eval(ROOT)?;
-
-    display_result(&result);
-    Ok(())
-}
+impl Solver for Day {
+    type Input = MonkeyGroup;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-fn part_2() -> anyhow::Result<()> {
-    println!("Part 2:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        MonkeyGroup::from_str(input)
+    }
 
-    let monkeys = MonkeyGroup::from_str(&input)?;
-    let result = monkeys.solve_human_value()?;
+    fn part_1(monkeys: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result = monkeys.eval(ROOT)?;
+        Ok(result)
+    }
 
-    display_result(&result);
-    Ok(())
+    fn part_2(monkeys: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let result = monkeys.solve_human_value()?;
+        Ok(result)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -43,25 +36,26 @@ enum MonkeyValue {
 }
 
 impl MonkeyValue {
-    pub fn from_str_with_key(s: &str) -> Result<(String, Self), anyhow::Error> {
-        let (id, value) = s.split_once(':').context("Id and value not separated by :")?;
-        let mut split = value.trim().split_whitespace();
+    pub fn from_line(line: &Line<'_>) -> Result<(String, Self), ParseError> {
+        let (id, value) = line.key_value(":")?;
+        let mut split = value.split_whitespace();
 
-        let first_segment = split.next().context("No first segment")?;
-        if first_segment.chars().all(|c| c.is_digit(10)) {
-            let num = first_segment.parse::<i64>()?;
+        let first_segment = split.next().ok_or_else(|| line.error("No first segment"))?;
+        if first_segment.chars().all(|c| c.is_ascii_digit()) {
+            let num = line.parse_part::<i64>(first_segment)?;
             return Ok((id.to_string(), Self::Number(num)));
         }
 
-        let operation = match split.next().context("No operation found")? {
+        let operation_segment = split.next().ok_or_else(|| line.error("No operation found"))?;
+        let operation = match operation_segment {
             "+" => Operation::Add,
             "-" => Operation::Sub,
             "*" => Operation::Mul,
             "/" => Operation::Div,
-            op => return Err(anyhow::anyhow!("Op not recognized: {op}")),
+            op => return Err(line.error_at(operation_segment, format!("Op not recognized: {op}"))),
         };
 
-        let third_segment = split.next().context("No third segment")?;
+        let third_segment = split.next().ok_or_else(|| line.error("No third segment"))?;
         Ok((id.to_string(), Self::Operation(first_segment.to_string(), operation, third_segment.to_string())))
     }
 }
@@ -100,7 +94,7 @@ impl<'a> MonkeyResult<'a> {
     }
 }
 
-struct MonkeyGroup {
+pub struct MonkeyGroup {
     monkeys: HashMap<String, MonkeyValue>,
 }
 
@@ -148,7 +142,7 @@ impl MonkeyGroup {
     pub fn solve_human_value(&self) -> Result<i64, anyhow::Error> {
         let root = self.monkeys
             .get(ROOT)
-            .with_context(|| format!("No root monkey found"))?;
+            .context("No root monkey found")?;
         let results = match root {
             MonkeyValue::Operation(lhs, _, rhs) =>
                 (
@@ -172,7 +166,7 @@ impl MonkeyGroup {
 
         let monkey = self.monkeys
             .get(monkey_id)
-            .with_context(|| format!("No root monkey found"))?;
+            .context("No root monkey found")?;
         let operation = match monkey {
             MonkeyValue::Operation(lhs, op, rhs) =>
                 (
@@ -207,8 +201,8 @@ impl FromStr for MonkeyGroup {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let monkeys = s.lines()
-            .map(MonkeyValue::from_str_with_key)
+        let monkeys = parse::lines(s)
+            .map(|line| MonkeyValue::from_line(&line))
             .collect::<Result<HashMap<_, _>, _>>()?;
         Ok(Self {
             monkeys,
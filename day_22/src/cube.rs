@@ -0,0 +1,359 @@
+use std::fmt::{self, Display};
+
+use anyhow::{bail, Context};
+use enum_map::EnumMap;
+use xmas::{direction::{Direction, QuarterRotation, DIRECTIONS}, direction3d::Direction3D, map2d::Map2D, point2d::Point2D, rotation::CubeRotation};
+
+use crate::{Movement, PasswordMap};
+
+/// Side length of the faces of the cube the tiles of `map` fold into.
+pub fn detect_face_size(map: &Map2D) -> anyhow::Result<isize> {
+    let tiles = map.iter().filter(|&&tile| tile != b' ').count() as isize;
+    let face_size = ((tiles / 6) as f64).sqrt().round() as isize;
+    if face_size == 0 || 6 * face_size * face_size != tiles {
+        bail!("{tiles} tiles can't be split into 6 square faces");
+    }
+    Ok(face_size)
+}
+
+/// Where walking off a face over one of its edges leads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stitch {
+    /// Index of the face on the other side.
+    pub face: usize,
+    /// Edge of that face walked in through, as seen on the map.
+    pub edge: Direction,
+    /// Turn that heading takes crossing over.
+    pub rotation: QuarterRotation,
+}
+
+/// The password map folded into a cube, with any of the 11 nets a cube can unfold into.
+#[derive(Debug, Clone)]
+pub struct PasswordCubeMap {
+    unfolded: PasswordMap,
+    face_size: isize,
+    /// Faces in the order they were folded in, starting with the one the start is on.
+    faces: Vec<Face>,
+    /// Index of the face in each face sized block of the map.
+    face_ids: Map2D<Option<usize>>,
+    /// Where each edge of each face leads.
+    stitches: Vec<EnumMap<Direction, Stitch>>,
+}
+
+impl PasswordCubeMap {
+    pub fn new(unfolded: PasswordMap) -> anyhow::Result<Self> {
+        let face_size = detect_face_size(&unfolded.map)?;
+        Self::with_face_size(unfolded, face_size)
+    }
+
+    pub fn with_face_size(unfolded: PasswordMap, face_size: isize) -> anyhow::Result<Self> {
+        let face_extent = Point2D::splat(face_size);
+        let blocks = (unfolded.map.size() + face_extent - Point2D::splat(1)).div_euclid(face_extent);
+        let mut face_ids = Map2D::new_filled(blocks, None);
+        let mut faces: Vec<Face> = Vec::new();
+        let mut sides = EnumMap::<Direction3D, Option<usize>>::default();
+
+        // Folds the net up from the start, each face tipping over the edge it shares with the last
+        let mut pending = vec![(unfolded.start.div_euclid(face_extent), Orientation::default())];
+        while let Some((block, orientation)) = pending.pop() {
+            if face_ids.get_tile(block) != Some(&None) {
+                continue;
+            }
+            let origin = block.scale(face_extent);
+            let tiles = match unfolded.map.sub_map(origin, face_extent) {
+                Some(tiles) if tiles.iter().all(|&tile| tile != b' ') => tiles,
+                Some(tiles) if tiles.iter().any(|&tile| tile != b' ') => {
+                    bail!("Face at {origin} is only partly on the map");
+                },
+                _ => continue,
+            };
+
+            let side = orientation.normal();
+            if let Some(other) = sides[side] {
+                bail!("Faces at {} and {origin} both fold onto the {side:?} side", faces[other].origin);
+            }
+            sides[side] = Some(faces.len());
+            face_ids.set_tile(block, Some(faces.len()));
+            for dir in DIRECTIONS {
+                pending.push((block + dir.as_point(), orientation.move_towards(dir)));
+            }
+            faces.push(Face { origin, orientation, tiles });
+        }
+
+        let sides = sides.into_iter()
+            .map(|(side, face)| face.map(|face| (side, face)).with_context(|| format!("No face folds onto the {side:?} side")))
+            .collect::<anyhow::Result<EnumMap<_, _>>>()?;
+        let stitches = faces.iter()
+            .map(|face| EnumMap::from_fn(|edge| face.stitch(edge, &faces, &sides)))
+            .collect();
+
+        Ok(Self { unfolded, face_size, faces, face_ids, stitches })
+    }
+
+    pub fn face_size(&self) -> isize {
+        self.face_size
+    }
+
+    /// Where walking off face `face` over `edge` leads.
+    pub fn stitch(&self, face: usize, edge: Direction) -> Option<Stitch> {
+        self.stitches.get(face).map(|stitches| stitches[edge])
+    }
+
+    fn face_at(&self, point: Point2D) -> Option<usize> {
+        let block = point.div_euclid(Point2D::splat(self.face_size));
+        self.face_ids.get_tile(block).copied().flatten()
+    }
+
+    /// Every position and heading taken following `movements`, ending with the final one.
+    pub fn travel(&self, movements: &[Movement]) -> Vec<(Point2D, Direction)> {
+        let mut pos = self.unfolded.start;
+        let mut dir = Direction::Right;
+        let mut path = vec![(pos, dir)];
+
+        for movement in movements {
+            match movement {
+                Movement::Turn(turn) => dir = dir.turn(*turn),
+                Movement::Move(steps) => {
+                    for _ in 0..*steps {
+                        let (next_pos, next_dir) = self.step(pos, dir);
+                        if self.unfolded.map.get_tile(next_pos) == Some(&b'#') {
+                            break;
+                        }
+                        (pos, dir) = (next_pos, next_dir);
+                        path.push((pos, dir));
+                    }
+                },
+            }
+            if let Some(last) = path.last_mut() {
+                *last = (pos, dir);
+            }
+        }
+        path
+    }
+
+    /// Tile in front of `pos` and the heading there, over an edge onto another face if needed.
+    fn step(&self, pos: Point2D, dir: Direction) -> (Point2D, Direction) {
+        let next = pos + dir.as_point();
+        let Some(face_id) = self.face_at(pos) else {
+            return (next, dir);
+        };
+        let face = &self.faces[face_id];
+        let relative = next - face.origin;
+        if face.tiles.is_inside(relative) {
+            return (next, dir);
+        }
+
+        let stitch = self.stitches[face_id][dir];
+        let target = &self.faces[stitch.face];
+        let wrapped = relative.rem_euclid(Point2D::splat(self.face_size));
+        let landed = target.tiles.rotated_point(wrapped, stitch.rotation) + target.origin;
+        (landed, dir.turn_rotation(stitch.rotation))
+    }
+}
+
+impl Display for PasswordCubeMap {
+    /// The stitching table, where each edge of each face leads.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Faces of size {}:", self.face_size)?;
+        for (id, (face, stitches)) in self.faces.iter().zip(&self.stitches).enumerate() {
+            writeln!(f, "{id}: at {} on the {:?} side", face.origin, face.orientation.normal())?;
+            for edge in DIRECTIONS {
+                let Stitch { face, edge: entered, rotation } = stitches[edge];
+                writeln!(f, "  {edge:?} -> {face} through {entered:?}, turning {rotation:?}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Face {
+    origin: Point2D,
+    orientation: Orientation,
+    /// The face's own tiles, as laid out in the unfolded map.
+    tiles: Map2D,
+}
+
+impl Face {
+    /// Where walking over `edge` leads, given the face on each side of the cube.
+    fn stitch(&self, edge: Direction, faces: &[Face], sides: &EnumMap<Direction3D, usize>) -> Stitch {
+        let folded = self.orientation.move_towards(edge);
+        let face = sides[folded.normal()];
+        let rotation = faces[face].orientation.get_rotation(&folded)
+            .expect("Orientations of the same side only differ by a turn");
+        let heading = edge.turn_rotation(rotation);
+        Stitch { face, edge: heading.turn_rotation(QuarterRotation::TurnAround), rotation }
+    }
+}
+
+/// How a face of the unfolded map sits on the cube, as the rotation taking the map's own
+/// directions, with [`Direction3D::Front`] coming out of it, to the cube's.
+#[derive(Debug, Clone, Default)]
+struct Orientation(CubeRotation);
+
+impl Orientation {
+    /// Side of the cube the face is on.
+    fn normal(&self) -> Direction3D {
+        self.0.apply_direction(Direction3D::Front)
+    }
+
+    /// Orientation of the face next to this one towards `direction_2d` on the map, once folded.
+    fn move_towards(&self, direction_2d: Direction) -> Self {
+        // Folding tips the front of the map towards that edge, and that edge towards the back
+        let (right, down) = match direction_2d {
+            Direction::Up => (Direction3D::Right, Direction3D::Front),
+            Direction::Left => (Direction3D::Front, Direction3D::Down),
+            Direction::Down => (Direction3D::Right, Direction3D::Back),
+            Direction::Right => (Direction3D::Back, Direction3D::Down),
+        };
+        let fold = CubeRotation::from_axes(right, down).expect("Fold axes are perpendicular");
+        Self(fold.then(self.0))
+    }
+
+    /// Turn needed to go from this orientation to `new_orientation` of the same side.
+    fn get_rotation(&self, new_orientation: &Orientation) -> Option<QuarterRotation> {
+        new_orientation.0.then(self.0.inverse()).to_quarter_rotation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+    use xmas::point3d::Point3D;
+
+    use super::*;
+
+    /// The 11 nets of a cube, one character per face.
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    /// Tiny linear congruential generator, so the walls and walks are the same on every run.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, below: u64) -> u64 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 33) % below
+        }
+    }
+
+    /// `net` scaled up to faces of `face_size` tiles, with a few walls.
+    fn build_map(net: &Map2D, face_size: isize, rng: &mut Lcg) -> PasswordMap {
+        let map = Map2D::from_fn(net.size().scale(Point2D::splat(face_size)), |point| {
+            match net.get_tile(point.div_euclid(Point2D::splat(face_size))) {
+                Some(b'#') if rng.next(8) == 0 => b'#',
+                Some(b'#') => b'.',
+                _ => b' ',
+            }
+        });
+        PasswordMap::new(map).unwrap()
+    }
+
+    fn random_movements(rng: &mut Lcg) -> Vec<Movement> {
+        (0..200)
+            .map(|i| match i % 2 {
+                0 => Movement::Move(rng.next(12)),
+                _ if rng.next(2) == 0 => Movement::Turn(Direction::Left),
+                _ => Movement::Turn(Direction::Right),
+            })
+            .collect()
+    }
+
+    /// Walks the surface of the cube in 3D without any stitching, in doubled coordinates so tile
+    /// centers are odd and the faces lie at plus or minus `face_size`.
+    fn brute_force_travel(cube: &PasswordCubeMap, movements: &[Movement]) -> Vec<(Point2D, Direction)> {
+        let size = cube.face_size;
+        let mut on_cube = HashMap::new();
+        let mut on_map = HashMap::new();
+        for face in &cube.faces {
+            for (local, _) in face.tiles.iter_with_points() {
+                let point = face.orientation.0.apply(Point3D(2 * local.0 - size + 1, 2 * local.1 - size + 1, size));
+                on_cube.insert(face.origin + local, point);
+                on_map.insert(point, (face.origin + local, face));
+            }
+        }
+        let to_map = |pos: Point3D, heading: Point3D| {
+            let (point, face) = on_map[&pos];
+            let (dir, _) = face.orientation.0.inverse().apply(heading).xy().try_get_direction().unwrap();
+            (point, dir)
+        };
+
+        let start = &cube.faces[0];
+        let mut pos = on_cube[&cube.unfolded.start];
+        let mut heading = start.orientation.0.apply(Direction3D::Right.as_point());
+        let mut normal = start.orientation.0.apply(Direction3D::Front.as_point());
+        let mut path = vec![to_map(pos, heading)];
+        for movement in movements {
+            match movement {
+                Movement::Turn(Direction::Right) => heading = normal.cross(heading),
+                Movement::Turn(_) => heading = heading.cross(normal),
+                Movement::Move(steps) => {
+                    for _ in 0..*steps {
+                        let (mut next, mut next_heading, mut next_normal) = (pos + heading * 2, heading, normal);
+                        if next.dot(heading) > size {
+                            // Over the edge and down the next face
+                            (next, next_heading, next_normal) = (pos + heading - normal, -normal, heading);
+                        }
+                        if cube.unfolded.map.get_tile(on_map[&next].0) == Some(&b'#') {
+                            break;
+                        }
+                        (pos, heading, normal) = (next, next_heading, next_normal);
+                        path.push(to_map(pos, heading));
+                    }
+                },
+            }
+            *path.last_mut().unwrap() = to_map(pos, heading);
+        }
+        path
+    }
+
+    #[rstest]
+    fn folds_every_net(#[values(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10)] net: usize) {
+        let base: Map2D = NETS[net].parse().unwrap();
+        let mut rng = Lcg(net as u64);
+        let variants = [base.clone(), base.flipped_horizontally()].into_iter()
+            .flat_map(|net| xmas::direction::QUARTER_ROTATIONS.map(|rotation| net.rotated(rotation)));
+
+        for net in variants {
+            let map = build_map(&net, 3, &mut rng);
+            let cube = PasswordCubeMap::new(map).unwrap();
+            assert_eq!(cube.face_size(), 3);
+
+            // Every edge leads to a neighboring face, and back through the same edge
+            for face in 0..6 {
+                for edge in DIRECTIONS {
+                    let stitch = cube.stitch(face, edge).unwrap();
+                    assert_ne!(stitch.face, face);
+                    let back = cube.stitch(stitch.face, stitch.edge).unwrap();
+                    assert_eq!((back.face, back.edge), (face, edge), "\n{cube}");
+                }
+            }
+
+            let movements = random_movements(&mut rng);
+            assert_eq!(cube.travel(&movements), brute_force_travel(&cube, &movements), "\n{cube}");
+        }
+    }
+
+    #[rstest]
+    #[case("#...\n####\n#...", true)]
+    #[case("####\n####", false)]
+    #[case("#####\n#....", false)]
+    fn rejects_shapes_that_do_not_fold(#[case] net: &str, #[case] folds: bool) {
+        let net: Map2D = net.parse().unwrap();
+        let map = build_map(&net, 2, &mut Lcg(0));
+        assert_eq!(PasswordCubeMap::new(map).is_ok(), folds);
+    }
+}
@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use anyhow::{self, Context};
+use xmas::{direction::Direction, map2d::Map2D, point2d::Point2D, record, solver::Solver};
+use regex_static::{lazy_regex, Regex, once_cell::sync::Lazy};
+use cube::PasswordCubeMap;
+
+pub mod cube;
+pub mod route;
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = (PasswordMap, Vec<Movement>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let (map, movements) = input.split_once("\n\n").context("No movements found")?;
+        let map = PasswordMap::from_str(map)?;
+        let movements = Movement::many_from_str(movements)?;
+        Ok((map, movements))
+    }
+
+    fn part_1((map, movements): &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let path = map.travel(movements);
+        let &(point, dir) = path.last().context("Empty path")?;
+        Ok(calculate_result(point, dir))
+    }
+
+    fn part_2((map, movements): &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let cube_map = PasswordCubeMap::new(map.clone())?;
+        let path = cube_map.travel(movements);
+        let &(point, dir) = path.last().context("Empty path")?;
+
+        if let Some(settings) = record::settings() {
+            let routes = route::side_by_side(
+                &route::draw_route(&map.map, &map.travel(movements)),
+                &route::draw_route(&map.map, &path),
+            );
+            route::record(&routes, &settings)?;
+        }
+        Ok(calculate_result(point, dir))
+    }
+}
+
+static MOVEMENT_REGEX: Lazy<Regex> = lazy_regex!(r"\d+|R|L");
+
+fn calculate_result(position: Point2D, direction: Direction) -> u64 {
+    let y_value = position.1.unsigned_abs() as u64 + 1;
+    let y_result = 1000 * y_value;
+
+    let x_value = position.0.unsigned_abs() as u64 + 1;
+    let x_result = 4 * x_value;
+
+    let dir = calculate_dir_score(direction);
+    x_result + y_result + dir
+}
+
+fn calculate_dir_score(dir: Direction) -> u64 {
+    match dir {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Movement {
+    Move(u64),
+    Turn(Direction),
+}
+
+impl Movement {
+    fn many_from_str(s: &str) -> Result<Vec<Self>, anyhow::Error> {
+        MOVEMENT_REGEX
+            .find_iter(s)
+            .map(|m| Self::from_str(m.as_str()))
+            .collect()
+    }
+
+    fn from_str(s: &str) -> Result<Self, anyhow::Error> {
+        let movement = match s {
+            "R" => Self::Turn(Direction::Right),
+            "L" => Self::Turn(Direction::Left),
+            _ => Self::Move(s.parse::<u64>().context("Not R, L or a unsigned int")?),
+        };
+        Ok(movement)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PasswordMap {
+    map: Map2D,
+    start: Point2D,
+}
+
+impl PasswordMap {
+    fn new(map: Map2D) -> Result<Self, anyhow::Error> {
+        let start = map.row(0).iter()
+            .position(|&tile| tile == b'.')
+            .map(|x| Point2D(x as isize, 0))
+            .context("No starting position")?;
+        Ok(Self { map, start })
+    }
+
+    /// Every position and heading taken following `movements`, ending with the final one.
+    pub fn travel(&self, movements: &[Movement]) -> Vec<(Point2D, Direction)> {
+        let mut pos = self.start;
+        let mut dir = Direction::Right;
+        let mut path = vec![(pos, dir)];
+        for movement in movements {
+            match movement {
+                Movement::Turn(turn) => dir = dir.turn(*turn),
+                Movement::Move(steps) => {
+                    let mut steps = *steps;
+                    let mut would_be_at = pos;
+                    while steps > 0 {
+                        would_be_at = self.wrap_pos(would_be_at + dir.as_point());
+                        match self.map.get_tile(would_be_at) {
+                            Some(b'.') => {
+                                steps -= 1;
+                                pos = would_be_at;
+                                path.push((pos, dir));
+                            },
+                            Some(b'#') => break,
+                            _ => (),
+                        }
+                    }
+                },
+            }
+            if let Some(last) = path.last_mut() {
+                *last = (pos, dir);
+            }
+        }
+        path
+    }
+
+    fn wrap_pos(&self, point: Point2D) -> Point2D {
+        point.rem_euclid(self.map.size())
+    }
+}
+
+impl FromStr for PasswordMap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let width = s.lines().map(|l| l.len()).max().unwrap_or(0) as isize;
+        let height = s.lines().count() as isize;
+        let mut map = Map2D::new_filled(Point2D(width, height), b' ');
+
+        for (y, line) in s.lines().enumerate() {
+            for (x, tile) in line.bytes().enumerate() {
+                let point = Point2D(x as isize, y as isize);
+                map.set_tile(point, tile);
+            }
+        }
+        PasswordMap::new(map)
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use anyhow::Context;
+use xmas::{direction::Direction, image::{Image, ImageError, Palette, BLACK, RED, WHITE}, map2d::Map2D, point2d::Point2D, record::RecordSettings};
+
+/// Columns of empty space between boards drawn side by side.
+const GAP: isize = 4;
+
+/// Character drawn where the route went through a tile facing `direction`.
+pub fn facing_char(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Left => '<',
+        Direction::Down => 'v',
+        Direction::Right => '>',
+    }
+}
+
+/// The board with `route` drawn over it, each tile showing the last heading it was left with.
+pub fn draw_route(board: &Map2D, route: &[(Point2D, Direction)]) -> Map2D<char> {
+    let mut map = board.map_tiles(|&tile| tile as char);
+    for &(point, direction) in route {
+        map.set_tile(point, facing_char(direction));
+    }
+    map
+}
+
+/// Both maps next to each other, tops aligned, to compare routes at a glance.
+pub fn side_by_side(left: &Map2D<char>, right: &Map2D<char>) -> Map2D<char> {
+    let size = Point2D(left.size().0 + GAP + right.size().0, left.size().1.max(right.size().1));
+    let mut map = Map2D::new_filled(size, ' ');
+    map.blit(left, Point2D(0, 0), None);
+    map.blit(right, Point2D(left.size().0 + GAP, 0), None);
+    map
+}
+
+pub fn palette() -> Palette {
+    ['^', '<', 'v', '>'].into_iter()
+        .fold(Palette::new(BLACK), |palette, tile| palette.with(tile, RED))
+        .with('.', [64, 64, 64])
+        .with('#', WHITE)
+}
+
+/// Writes `map` as text to a `.txt` file, or as an image to anything [`Image::save`] handles.
+pub fn export(map: &Map2D<char>, path: &Path, scale: usize) -> anyhow::Result<()> {
+    if path.extension().is_some_and(|extension| extension == "txt") {
+        fs::write(path, map.to_string()).with_context(|| format!("Error writing {}", path.display()))?;
+    } else {
+        Image::from_map(map, &palette()).scaled(scale).save(path)?;
+    }
+    Ok(())
+}
+
+/// Writes the flat and cube routes side by side into the recording directory, as text and as an
+/// image, returning where they went.
+pub fn record(routes: &Map2D<char>, settings: &RecordSettings) -> anyhow::Result<[PathBuf; 2]> {
+    fs::create_dir_all(&settings.dir).map_err(ImageError::from)?;
+    let paths = ["txt", "png"].map(|extension| settings.dir.join(format!("day_22_routes.{extension}")));
+    for path in &paths {
+        export(routes, path, settings.scale)?;
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_routes_next_to_each_other() {
+        let board: Map2D = "..#\n...".parse().unwrap();
+        let flat = draw_route(&board, &[(Point2D(0, 0), Direction::Right), (Point2D(1, 0), Direction::Down)]);
+        let cube = draw_route(&board, &[(Point2D(0, 1), Direction::Up)]);
+
+        assert_eq!(
+            side_by_side(&flat, &cube).to_string(),
+            ">v#    ..#\n...    ^..\n",
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::{collections::HashMap, str::FromStr};
+
+use xmas::{direction::{Direction::{self, *}, QuarterRotation}, image::WHITE, map2d::Map2D, point2d::Point2D, record::Recorder, solver::Solver, sparse_grid::SparseGrid, visualize::Simulation};
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = ElvesMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        ElvesMap::from_str(input)
+    }
+
+    fn part_1(elves: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut elves = elves.clone();
+        elves.recorder = Recorder::if_enabled("day_23_part_1");
+
+        elves.move_many_rounds(10);
+        let result = elves.empty_count();
+
+        if let Some(recorder) = elves.recorder.take() {
+            recorder.finish()?;
+        }
+
+        Ok(result)
+    }
+
+    fn part_2(elves: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut elves = elves.clone();
+        elves.recorder = Recorder::if_enabled("day_23_part_2");
+
+        let result = elves.count_rounds_until_stopping();
+
+        if let Some(recorder) = elves.recorder.take() {
+            recorder.finish()?;
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf;
+
+impl From<Elf> for char {
+    fn from(_: Elf) -> Self {
+        '#'
+    }
+}
+
+#[derive(Debug)]
+pub struct ElvesMap {
+    elves: SparseGrid<Elf>,
+    /// Gets a frame after each round.
+    recorder: Option<Recorder>,
+}
+
+impl Clone for ElvesMap {
+    /// The copy starts without a recorder, a recording only following a single run.
+    fn clone(&self) -> Self {
+        Self { elves: self.elves.clone(), recorder: None }
+    }
+}
+
+impl ElvesMap {
+    pub fn bounds(&self) -> Option<(Point2D, Point2D)> {
+        self.elves.bounds()
+    }
+
+    pub fn empty_count(&self) -> usize {
+        self.elves.area() - self.elves.len()
+    }
+
+    pub fn count_rounds_until_stopping(&mut self) -> usize {
+        let mut rounds = 0;
+        loop {
+            let moved = self.move_round(rounds);
+            rounds += 1;
+            if !moved {
+                return rounds;
+            }
+        }
+    }
+
+    pub fn move_many_rounds(&mut self, rounds: usize) {
+        for i in 0..rounds {
+            self.move_round(i);
+        }
+    }
+
+    pub fn move_round(&mut self, round_i: usize) -> bool {
+        let proposed_moves = self.elves.points()
+            .filter(|&elf| self.should_move(elf))
+            .flat_map(|elf| self.propose_move(elf, round_i).map(|p| (elf, p)))
+            .collect::<HashMap<_, _>>();
+        // for (elf, proposed) in &proposed_moves {
+        //     if elf != proposed {
+        //         println!("Elf {elf} proposed {proposed}");
+        //     }
+        // }
+        if proposed_moves.is_empty() {
+            return false;
+        }
+
+        let mut moves_count = HashMap::<Point2D, usize>::new();
+        for proposed in proposed_moves.values() {
+            let entry = moves_count.entry(*proposed).or_default();
+            *entry += 1;
+        }
+
+        // dbg!(&proposed_moves);
+        // dbg!(&moves_count);
+        let mut any_moved = false;
+        for (elf, proposed) in proposed_moves
+            .iter()
+            .filter(|&(_, proposed)| {
+                let count = *moves_count.get(proposed).unwrap_or(&0);
+                // println!("Count for {proposed} = {count}");
+                count <= 1
+            })
+        {
+            // println!("Moving {} -> {}", elf, proposed);
+            let removed = self.elves.remove(*elf);
+            assert!(removed.is_some());
+            let replaced = self.elves.set(*proposed, Elf);
+            assert!(replaced.is_none());
+            any_moved = true;
+        }
+
+        if let Some(recorder) = &mut self.recorder {
+            recorder.step_points(self.elves.points(), WHITE);
+        }
+        any_moved
+    }
+
+    fn propose_move(&self, elf: Point2D, round_i: usize) -> Option<Point2D> {
+        Self::check_dirs(round_i)
+            .flat_map(|dir| self.can_move(elf, dir))
+            .next()
+    }
+
+    fn check_dirs(round_i: usize) -> impl Iterator<Item = Direction> {
+        (0..CHECK_DIRS.len())
+            .map(move |i| (i + round_i) % CHECK_DIRS.len())
+            .map(|i| CHECK_DIRS[i])
+    }
+
+    fn can_move(&self, elf: Point2D, dir: Direction) -> Option<Point2D> {
+        let check_points = [
+            dir.as_point(),
+            dir.combined(dir.turn_rotation(QuarterRotation::Left)),
+            dir.combined(dir.turn_rotation(QuarterRotation::Right)),
+        ].map(|p| elf + p);
+        if check_points.iter().all(|&p| !self.elves.contains(p)) {
+            Some(elf + dir.as_point())
+        } else {
+            None
+        }
+    }
+
+    fn should_move(&self, elf: Point2D) -> bool {
+        self.elves.neighbors_8(elf).next().is_some()
+    }
+
+    /// The rectangle around every elf.
+    pub fn to_map(&self) -> Map2D<char> {
+        let Some((min, max)) = self.bounds() else {
+            return Map2D::new_filled(Point2D(0, 0), '.');
+        };
+        let mut map = Map2D::new_filled(max - min + Point2D(1, 1), '.');
+        for elf in self.elves.points() {
+            map.set_tile(elf - min, '#');
+        }
+        map
+    }
+}
+
+impl FromStr for ElvesMap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elves = SparseGrid::new();
+        for (y, line) in s.lines().enumerate() {
+            for (x, _) in line.chars().enumerate().filter(|&(_, c)| c == '#') {
+                elves.set(Point2D(x as isize, y as isize), Elf);
+            }
+        }
+        Ok(Self { elves, recorder: None })
+    }
+}
+
+/// The elves spreading out round by round, for the visualizer.
+pub struct ElvesSimulation {
+    elves: ElvesMap,
+    round: usize,
+}
+
+impl Simulation for ElvesSimulation {
+    /// A round at a time, until no elf moves.
+    fn step(&mut self) -> bool {
+        let moved = self.elves.move_round(self.round);
+        self.round += 1;
+        moved
+    }
+
+    fn render(&self) -> Map2D<char> {
+        self.elves.to_map()
+    }
+}
+
+pub fn simulation(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    Ok(Box::new(ElvesSimulation { elves: Day::parse(input)?, round: 0 }))
+}
+
+const CHECK_DIRS: [Direction; 4] = [
+    Up,
+    Down,
+    Left,
+    Right,
+];
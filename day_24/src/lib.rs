@@ -1,11 +1,43 @@
-use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet}, rc::Rc, str::FromStr};
+use std::{collections::HashSet, str::FromStr};
 
 use anyhow::Context;
 use num::integer::lcm;
-use xmas::{direction::{Direction, DIRECTIONS}, keyed_ord::KeyedOrd, map2d::Map2D, num::wrap_val, point2d::Point2D};
+use xmas::{direction::Direction, image::{Image, Palette, BLACK, RED}, map2d::Map2D, num::wrap_val, point2d::Point2D, record::Recorder, search::{Found, Search}, solver::Solver, visualize::Simulation};
 
 pub type Minutes = isize;
 
+pub struct Day;
+
+impl Solver for Day {
+    type Input = BlizzardMap;
+    type Answer1 = Minutes;
+    type Answer2 = Minutes;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        BlizzardMap::from_str(input)
+    }
+
+    fn part_1(map: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let mut recorder = Recorder::if_enabled("day_24_part_1");
+        let result = map.navigate(recorder.as_mut()).context("No path found")?;
+
+        if let Some(recorder) = recorder {
+            recorder.finish()?;
+        }
+        Ok(result)
+    }
+
+    fn part_2(map: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let mut recorder = Recorder::if_enabled("day_24_part_2");
+        let result = map.navigate_back_and_forth(recorder.as_mut()).context("No path found")?;
+
+        if let Some(recorder) = recorder {
+            recorder.finish()?;
+        }
+        Ok(result)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BlizzardMap {
     map: Map2D,
@@ -16,122 +48,102 @@ pub struct BlizzardMap {
 }
 
 impl BlizzardMap {
-    pub fn navigate(&self) -> Option<isize> {
-        self.navigate_with_options(0, self.start_pos, self.target_pos)
-    }
-
-    pub fn navigate_back_and_forth(&self) -> Option<isize> {
-        self.navigate_with_options(0, self.start_pos, self.target_pos)
-            .and_then(|time| {
-                println!("First trip took {}", time);
-                match self.navigate_with_options(time, self.target_pos, self.start_pos) {
-                    Some(back_time) => {
-                        println!("Back trip took {}, time is now: {}", back_time, time + back_time);
-                        Some(time + back_time)
-                    },
-                    None => None,
-                }
-            })
-            .and_then(|time| {
-                match self.navigate_with_options(time, self.start_pos, self.target_pos) {
-                    Some(second_trip_time) => {
-                        println!("Second trip took {}, time is now: {}", second_trip_time, time + second_trip_time);
-                        Some(time + second_trip_time)
-                    },
-                    None => None,
-                }
-            })
+    /// Every minute of the trip gets a frame in `recorder`, if there's one.
+    pub fn navigate(&self, recorder: Option<&mut Recorder>) -> Option<isize> {
+        self.navigate_with_options(0, self.start_pos, self.target_pos, recorder)
     }
 
-    pub fn navigate_with_options(&self, start_time: Minutes, from: Point2D, to: Point2D) -> Option<isize> {
-        // println!("Trip: {} -> {} @ time {}", from, to, start_time);
-        let mut open_list = BinaryHeap::new();
-        open_list.push(Breadcrumb::new(from, start_time).as_priority_with_target(to));
-
-        let mut closed_list = HashSet::new();
-
-        // let mut i = 0;
-        while let Some(candidate) = open_list.pop() {
-            // if i % 100_000 == 0 {
-            //     println!("Next cost is: {}", candidate.key.0);
-            //     println!("Queue has {} element/s", open_list.len());
-            //     println!("Distance: {}", candidate.value.pos.manhattan_distance(self.target_pos));
-            // }
-            // i += 1;
+    pub fn navigate_back_and_forth(&self, mut recorder: Option<&mut Recorder>) -> Option<isize> {
+        let time = self.navigate_with_options(0, self.start_pos, self.target_pos, recorder.as_deref_mut())?;
+        let time = time + self.navigate_with_options(time, self.target_pos, self.start_pos, recorder.as_deref_mut())?;
+        let time = time + self.navigate_with_options(time, self.start_pos, self.target_pos, recorder)?;
+        Some(time)
+    }
 
-            if candidate.value.pos == to {
-                return Some(candidate.value.time - start_time);
+    pub fn navigate_with_options(
+        &self,
+        start_time: Minutes,
+        from: Point2D,
+        to: Point2D,
+        recorder: Option<&mut Recorder>,
+    ) -> Option<isize> {
+        let found = self.find_trip(start_time, from, to, recorder.is_some())?;
+        if let (Some(recorder), Some(path)) = (recorder, &found.path) {
+            for breadcrumb in path {
+                recorder.step(|| self.image_at(breadcrumb.time, breadcrumb.pos));
             }
+        }
+        Some(found.goal.time - start_time)
+    }
 
-            let explored = ExploredPoint {
-                point: candidate.value.pos,
-                time: candidate.value.time % self.blizzard_loop_len,
-            };
-            if closed_list.contains(&explored) {
-                // println!("Already explored: {:?}", explored);
-                continue;
-            }
-            closed_list.insert(explored);
-
-            let candidate = Rc::new(candidate.value);
-
-            let next_time = candidate.time + 1;
-            let map_size = self.map.size();
-            let next_blizzard_positions = self.blizzards.iter()
-                .map(|b| b.position_after(next_time, map_size))
-                .collect::<HashSet<_>>();
-            
-            let at_pos = candidate.pos;
-            let adjacent = DIRECTIONS.iter()
-                .map(|d| at_pos + d.as_point())
-                .filter(|&p| self.map.get_tile(p).is_some_and(|t| t != &b'#'));
-
-            let next_candidates = Some(at_pos).into_iter()
-                .chain(adjacent)
-                .filter(|p| !next_blizzard_positions.contains(&p))
-                .map(|pos| Breadcrumb { pos, time: next_time, previous: Some(candidate.clone()) })
-                .map(|bc| bc.as_priority_with_target(to));
-
-            open_list.extend(next_candidates);
+    fn find_trip(&self, start_time: Minutes, from: Point2D, to: Point2D, with_path: bool) -> Option<Found<Breadcrumb, Minutes>> {
+        // Blizzards are back where they started after each loop, so those times are the same state
+        let mut search = Search::keyed_by(|candidate: &Breadcrumb| ExploredPoint {
+            point: candidate.pos,
+            time: candidate.time % self.blizzard_loop_len,
+        });
+        if with_path {
+            search = search.with_path();
         }
-        None
+
+        search.astar(
+            Breadcrumb::new(from, start_time),
+            |candidate| self.next_candidates(candidate),
+            |candidate| candidate.pos.manhattan_distance(to) as isize,
+            |candidate| candidate.pos == to,
+        ).ok()
     }
 
-    pub fn display_at(&self, time: Minutes) {
-        println!("Map @ minute {}", time);
-        let positions = self.blizzards
-            .iter()
-            .map(|b| b.after(time, self.map.size()))
-            .map(|b| (b.origin, b))
-            .collect::<HashMap<_, _>>();
-        for (y, row) in self.map.rows_iter().enumerate() {
-            let mut line = String::new();
-            for (point, &tile) in row.iter().enumerate()
-                .map(|(x, t)| (Point2D(x as isize, y as isize), t))
-            {
-                if tile == b'#' {
-                    line.push('#');
-                    continue;
-                }
-
-                let dir = match positions.get(&point) {
-                    Some(b) => b.dir,
-                    None => {
-                        line.push('.');
-                        continue;
-                    },
-                };
+    fn next_candidates(&self, candidate: &Breadcrumb) -> impl Iterator<Item = (Breadcrumb, Minutes)> + '_ {
+        const MOVE_COST: Minutes = 1;
+        let next_time = candidate.time + 1;
+        let map_size = self.map.size();
+        let next_blizzard_positions = self.blizzards.iter()
+            .map(|b| b.position_after(next_time, map_size))
+            .collect::<HashSet<_>>();
+
+        let at_pos = candidate.pos;
+        let adjacent = self.map.neighbors(at_pos)
+            .filter(|&(_, &tile)| tile != b'#')
+            .map(|(p, _)| p);
 
-                line.push(match dir {
+        Some(at_pos).into_iter()
+            .chain(adjacent)
+            .filter(move |p| !next_blizzard_positions.contains(p))
+            .map(move |pos| (Breadcrumb { pos, time: next_time }, MOVE_COST))
+    }
+
+    fn image_at(&self, time: Minutes, expedition: Point2D) -> Image {
+        let palette = Palette::new(BLACK).with('#', [128, 128, 128]);
+        let mut image = Image::from_map(&self.map, &palette);
+        for blizzard in &self.blizzards {
+            image.set(blizzard.position_after(time, self.map.size()), [80, 140, 220]);
+        }
+        image.set(expedition, RED);
+        image
+    }
+
+    /// The valley at minute `time`, with the amount of blizzards instead of their direction where
+    /// several of them overlap.
+    pub fn map_at(&self, time: Minutes, expedition: Option<Point2D>) -> Map2D<char> {
+        let mut map = self.map.map_tiles(|&tile| if tile == b'#' { '#' } else { '.' });
+        for blizzard in self.blizzards.iter().map(|b| b.after(time, self.map.size())) {
+            let tile = match map.get_tile(blizzard.origin) {
+                Some('.') => match blizzard.dir {
                     Direction::Up => '^',
                     Direction::Left => '<',
                     Direction::Down => 'v',
                     Direction::Right => '>',
-                });
-            }
-            println!("{}", line);
+                },
+                Some(&c) => c.to_digit(10).map_or('2', |n| char::from_digit(n + 1, 10).unwrap_or('+')),
+                None => continue,
+            };
+            map.set_tile(blizzard.origin, tile);
         }
-        println!();
+        if let Some(expedition) = expedition {
+            map.set_tile(expedition, 'E');
+        }
+        map
     }
 
     pub fn blizzard_loop_len(&self) -> Minutes {
@@ -170,12 +182,40 @@ impl FromStr for BlizzardMap {
             .collect();
 
         let blizzard_loop_len = lcm(map.width(), map.height()) as Minutes;
-        println!("Blizzards loop each {} step/s ({} and {})", blizzard_loop_len, map.width(), map.height());
+        // println!("Blizzards loop each {} step/s ({} and {})", blizzard_loop_len, map.width(), map.height());
 
         Ok(Self { map, start_pos, target_pos, blizzards, blizzard_loop_len })
     }
 }
 
+/// The expedition following the quickest way through the valley, for the visualizer.
+pub struct BlizzardTrip {
+    map: BlizzardMap,
+    path: Vec<Breadcrumb>,
+    minute: usize,
+}
+
+impl Simulation for BlizzardTrip {
+    /// A minute at a time, until the expedition reaches the target.
+    fn step(&mut self) -> bool {
+        self.minute = (self.minute + 1).min(self.path.len() - 1);
+        self.minute + 1 < self.path.len()
+    }
+
+    fn render(&self) -> Map2D<char> {
+        let breadcrumb = &self.path[self.minute];
+        self.map.map_at(breadcrumb.time, Some(breadcrumb.pos))
+    }
+}
+
+pub fn simulation(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let map = Day::parse(input)?;
+    let path = map.find_trip(0, map.start_pos, map.target_pos, true)
+        .and_then(|found| found.path)
+        .context("No path found")?;
+    Ok(Box::new(BlizzardTrip { map, path, minute: 0 }))
+}
+
 #[derive(Debug, Clone)]
 struct Blizzard {
     origin: Point2D,
@@ -198,21 +238,15 @@ impl Blizzard {
     }
 }
 
+#[derive(Debug, Clone)]
 struct Breadcrumb {
     pos: Point2D,
     time: Minutes,
-    previous: Option<Rc<Breadcrumb>>,
 }
 
 impl Breadcrumb {
     pub fn new(pos: Point2D, time: Minutes) -> Self {
-        Self { pos, time, previous: None }
-    }
-
-    pub fn as_priority_with_target(self, target: Point2D) -> KeyedOrd<Breadcrumb, Reverse<isize>> {
-        let distance = self.pos.manhattan_distance(target);
-        let ord = Reverse(distance as isize + self.time);
-        KeyedOrd::new(self, ord)
+        Self { pos, time }
     }
 }
 
@@ -221,3 +255,42 @@ struct ExploredPoint {
     point: Point2D,
     time: Minutes,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "#.######\n",
+        "#>>.<^<#\n",
+        "#.<..<<#\n",
+        "#>v.><>#\n",
+        "#<^v^^>#\n",
+        "######.#\n",
+    );
+
+    #[test]
+    fn solves_example_part_1() {
+        let map = Day::parse(EXAMPLE).unwrap();
+        assert_eq!(Day::part_1(&map).unwrap(), 18);
+    }
+
+    #[test]
+    fn solves_example_part_2() {
+        let map = Day::parse(EXAMPLE).unwrap();
+        assert_eq!(Day::part_2(&map).unwrap(), 54);
+    }
+
+    #[test]
+    fn simulates_example_trip() {
+        let mut trip = simulation(EXAMPLE).unwrap();
+        assert_eq!(trip.render().get_tile(Point2D(1, 0)), Some(&'E'));
+
+        let mut minutes = 1;
+        while trip.step() {
+            minutes += 1;
+        }
+        assert_eq!(minutes, 18);
+        assert_eq!(trip.render().get_tile(Point2D(6, 5)), Some(&'E'));
+    }
+}
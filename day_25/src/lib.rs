@@ -1,5 +1,29 @@
 use std::{fmt::Display, iter::Sum, str::FromStr};
 
+use xmas::{parse, solver::Solver};
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<SNAFU>;
+    type Answer1 = SNAFU;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part_1(snafus: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let result = snafus.iter().copied().sum::<SNAFU>();
+        Ok(result)
+    }
+
+    fn part_2(_: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        // There's no second puzzle on the last day
+        Ok("Merry Christmas!")
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SNAFU(i64);
 
@@ -94,4 +118,17 @@ mod tests {
         let val = SNAFU(snafu).to_string();
         assert_eq!(val, expected);
     }
+
+    #[test]
+    fn sums_example_snafus() {
+        const EXAMPLE: &str = concat!(
+            "1=-0-2\n", "12111\n", "2=0=\n", "21\n", "2=01\n", "111\n", "20012\n",
+            "112\n", "1=-1=\n", "1-12\n", "12\n", "1=\n", "122\n",
+        );
+
+        let snafus = Day::parse(EXAMPLE).unwrap();
+        let result = Day::part_1(&snafus).unwrap();
+        assert_eq!(result, SNAFU(4890));
+        assert_eq!(result.to_string(), "2=-1=0");
+    }
 }
@@ -0,0 +1,25 @@
+use xmas::solver::Solver;
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        for _line in input {
+            // Process lines
+        }
+
+        Ok(0)
+    }
+
+    fn part_2(_input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        Ok(0)
+    }
+}
@@ -0,0 +1,25 @@
+use xmas::{map2d::Map2D, solver::Solver};
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Map2D;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part_1(map: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        for (_point, _tile) in map.iter_with_points() {
+            // Process tiles
+        }
+
+        Ok(0)
+    }
+
+    fn part_2(_map: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        Ok(0)
+    }
+}
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use xmas::solver::Solver;
+
+#[derive(Debug, Clone)]
+pub struct Line(pub String);
+
+impl FromStr for Line {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.to_string()))
+    }
+}
+
+pub struct Day;
+
+impl Solver for Day {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        input.lines().map(Line::from_str).collect()
+    }
+
+    fn part_1(lines: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        for _line in lines {
+            // Process lines
+        }
+
+        Ok(0)
+    }
+
+    fn part_2(_lines: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        Ok(0)
+    }
+}
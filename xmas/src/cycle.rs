@@ -0,0 +1,129 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+/// A state machine that eventually repeats itself, with a value recorded after every step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<V> {
+    /// Steps taken before first entering the cycle.
+    pub start: usize,
+    /// Steps in each repetition of the cycle.
+    pub len: usize,
+    /// Value after each step, starting with the initial state, up to the first repeated one.
+    pub values: Vec<V>,
+}
+
+/// Runs `step` on `state` until a state with a key seen before comes up again, recording `value`
+/// for every state on the way. Two states must have the same key only if they lead to the same
+/// sequence of states, up to the value, so keep in the key everything the next steps depend on.
+///
+/// Gives up with `None` after `max_steps` steps without a repeat.
+pub fn find_cycle<S, K, V>(
+    mut state: S,
+    max_steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> V,
+) -> Option<Cycle<V>>
+where
+    K: Hash + Eq,
+{
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+
+    for steps in 0..=max_steps {
+        values.push(value(&state));
+        if let Some(start) = seen.insert(key(&state), steps) {
+            return Some(Cycle { start, len: steps - start, values });
+        }
+        step(&mut state);
+    }
+    None
+}
+
+impl<V> Cycle<V> {
+    /// Step in the first repetition that ends in the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+
+    /// Value after step `n`, assuming each repetition of the cycle adds the same amount to it.
+    ///
+    /// Returns `None` if the number of repetitions doesn't fit in `V`.
+    pub fn extrapolate(&self, n: usize) -> Option<V>
+    where
+        V: Copy + Add<Output = V> + Sub<Output = V> + Mul<Output = V> + TryFrom<usize>,
+    {
+        if let Some(&value) = self.values.get(n) {
+            return Some(value);
+        }
+
+        let per_repetition = self.values[self.start + self.len] - self.values[self.start];
+        let repetitions = V::try_from((n - self.start) / self.len).ok()?;
+        Some(self.values[self.equivalent_step(n)] + repetitions * per_repetition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    /// Counts up modulo 4 after a 3 step lead-in, summing the counter as it goes.
+    fn counter_cycle() -> Cycle<u64> {
+        find_cycle(
+            (0_u64, 0_u64),
+            100,
+            |(n, sum)| {
+                *n = if *n < 6 { *n + 1 } else { 3 };
+                *sum += *n;
+            },
+            |&(n, _)| n,
+            |&(_, sum)| sum,
+        ).unwrap()
+    }
+
+    #[test]
+    fn finds_start_and_length() {
+        let cycle = counter_cycle();
+
+        assert_eq!(cycle.start, 3);
+        assert_eq!(cycle.len, 4);
+    }
+
+    #[rstest]
+    #[case(2, 2)]
+    #[case(7, 3)]
+    #[case(13, 5)]
+    fn finds_equivalent_step(#[case] n: usize, #[case] expected: usize) {
+        assert_eq!(counter_cycle().equivalent_step(n), expected);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(5)]
+    #[case(8)]
+    #[case(25)]
+    #[case(1000)]
+    fn extrapolates_like_running_it(#[case] n: usize) {
+        let mut state = (0, 0);
+        for _ in 0..n {
+            state.0 = if state.0 < 6 { state.0 + 1 } else { 3 };
+            state.1 += state.0;
+        }
+
+        assert_eq!(counter_cycle().extrapolate(n), Some(state.1));
+    }
+
+    #[test]
+    fn gives_up_without_repeats() {
+        let cycle = find_cycle(0, 50, |n| *n += 1, |&n| n, |&n| n);
+        assert_eq!(cycle, None);
+    }
+}
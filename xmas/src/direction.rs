@@ -23,7 +23,7 @@ pub const DIRECTIONS_8: [Point2D; 8] = [
 ];
 
 impl Direction {
-    pub fn as_point(&self) -> Point2D {
+    pub const fn as_point(&self) -> Point2D {
         match self {
             Up => Point2D(0, -1),
             Left => Point2D(-1, 0),
@@ -36,26 +36,29 @@ impl Direction {
         self.as_point() + other.as_point()
     }
 
+    /// Turns as if `relative_turn` was given facing up, see [`QuarterRotation::from`].
     pub fn turn(&self, relative_turn: Self) -> Self {
-        let final_value = (self.value() + relative_turn.value()) as usize % DIRECTIONS.len();
-        DIRECTIONS[final_value]
+        self.turn_rotation(relative_turn.into())
     }
 
     pub fn turn_rotation(&self, rot: QuarterRotation) -> Self {
-        let final_value = (self.value() + rot.value()) as usize % DIRECTIONS.len();
-        DIRECTIONS[final_value]
+        let turns = self.clockwise_turns() + rot.clockwise_turns();
+        CLOCKWISE[turns as usize % CLOCKWISE.len()]
     }
 
-    fn value(&self) -> u8 {
+    /// Quarter turns clockwise from [`Up`].
+    fn clockwise_turns(&self) -> u8 {
         match self {
             Up => 0,
-            Left => 1,
+            Right => 1,
             Down => 2,
-            Right => 3,
+            Left => 3,
         }
     }
 }
 
+const CLOCKWISE: [Direction; 4] = [Up, Right, Down, Left];
+
 impl From<Direction> for Point2D {
     fn from(value: Direction) -> Self {
         value.as_point()
@@ -70,13 +73,69 @@ pub enum QuarterRotation {
     Left,
 }
 
+pub const QUARTER_ROTATIONS: [QuarterRotation; 4] = [
+    QuarterRotation::None,
+    QuarterRotation::Right,
+    QuarterRotation::TurnAround,
+    QuarterRotation::Left,
+];
+
 impl QuarterRotation {
-    fn value(&self) -> u8 {
+    /// Quarter turns clockwise, from 0 to 3.
+    pub fn clockwise_turns(&self) -> u8 {
         match self {
             Self::None => 0,
-            Self::Left => 1,
+            Self::Right => 1,
             Self::TurnAround => 2,
-            Self::Right => 3,
+            Self::Left => 3,
+        }
+    }
+
+    /// Rotation of `turns` clockwise quarter turns, negative ones going counter clockwise.
+    pub fn from_clockwise_turns(turns: isize) -> Self {
+        QUARTER_ROTATIONS[turns.rem_euclid(4) as usize]
+    }
+
+    /// This rotation followed by `other`.
+    pub fn then(&self, other: Self) -> Self {
+        Self::from_clockwise_turns((self.clockwise_turns() + other.clockwise_turns()) as isize)
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self::from_clockwise_turns(-(self.clockwise_turns() as isize))
+    }
+}
+
+impl From<Direction> for QuarterRotation {
+    /// The turn needed to face `direction` when facing up.
+    fn from(direction: Direction) -> Self {
+        Self::from_clockwise_turns(direction.clockwise_turns() as isize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_like_points_rotate() {
+        for direction in DIRECTIONS {
+            for rotation in QUARTER_ROTATIONS {
+                let turned = direction.turn_rotation(rotation);
+                assert_eq!(turned.as_point(), direction.as_point().rotated(rotation));
+                assert_eq!(turned.turn_rotation(rotation.inverse()), direction);
+                assert_eq!(direction.turn(turned), direction.turn_rotation(QuarterRotation::from(turned)));
+            }
+        }
+    }
+
+    #[test]
+    fn composes_quarter_rotations() {
+        for a in QUARTER_ROTATIONS {
+            assert_eq!(a.then(a.inverse()), QuarterRotation::None);
+            for b in QUARTER_ROTATIONS {
+                assert_eq!(Up.turn_rotation(a.then(b)), Up.turn_rotation(a).turn_rotation(b));
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::point3d::Point3D;
+use crate::{direction::Direction, point3d::Point3D};
 use enum_map::Enum;
 use Direction3D::*;
 
@@ -12,16 +12,16 @@ pub enum Direction3D {
     Back,
 }
 
-
+/// Axes are the same as for [`Direction`], x going right and y going down, with z going to the front.
 pub const DIRECTIONS_3D: [Direction3D; 6] = [Up, Left, Down, Right, Front, Back];
 
 impl Direction3D {
     pub fn as_point(&self) -> Point3D {
         match self {
             Up => Point3D(0, -1, 0),
-            Left => Point3D(1, 0, 0),
+            Left => Point3D(-1, 0, 0),
             Down => Point3D(0, 1, 0),
-            Right => Point3D(-1, 0, 0),
+            Right => Point3D(1, 0, 0),
             Front => Point3D(0, 0, 1),
             Back => Point3D(0, 0, -1),
         }
@@ -49,15 +49,44 @@ impl From<Direction3D> for Point3D {
     }
 }
 
+/// The same direction on the front facing plane.
+impl From<Direction> for Direction3D {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Up,
+            Direction::Left => Left,
+            Direction::Down => Down,
+            Direction::Right => Right,
+        }
+    }
+}
+
 impl TryFrom<Point3D> for Direction3D {
     type Error = &'static str;
 
     fn try_from(value: Point3D) -> Result<Self, Self::Error> {
         Ok(match value {
             Point3D(x, 0, 0) if x != 0 => if x > 0 { Direction3D::Right } else { Direction3D::Left },
-            Point3D(0, y, 0) if y != 0 => if y > 0 { Direction3D::Up } else { Direction3D::Down },
+            Point3D(0, y, 0) if y != 0 => if y > 0 { Direction3D::Down } else { Direction3D::Up },
             Point3D(0, 0, z) if z != 0 => if z > 0 { Direction3D::Front } else { Direction3D::Back },
             _ => return Err("Cannot convert vector to direction"),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::DIRECTIONS;
+
+    #[test]
+    fn converts_back_and_forth() {
+        for direction in DIRECTIONS_3D {
+            assert_eq!(Direction3D::try_from(direction.as_point()), Ok(direction));
+            assert_eq!(direction.inverse().as_point(), -direction.as_point());
+        }
+        for direction in DIRECTIONS {
+            assert_eq!(Direction3D::from(direction).as_point(), direction.as_point().with_z(0));
+        }
+    }
+}
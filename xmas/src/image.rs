@@ -0,0 +1,297 @@
+use std::{collections::HashMap, fs::File, io::{self, BufWriter, Read, Write}, path::{Path, PathBuf}};
+
+use thiserror::Error;
+
+use crate::{map2d::Map2D, point2d::Point2D};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const RED: Rgb = [220, 50, 47];
+
+#[derive(Debug, Error)]
+pub enum ImageError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Png(#[from] png::EncodingError),
+    #[error(transparent)]
+    Gif(#[from] gif::EncodingError),
+    #[error("Image of {0} pixels is too large for a GIF")]
+    TooLarge(Point2D),
+    #[error("Unknown image format for {0}, expected a .png or .ppm file")]
+    UnknownFormat(PathBuf),
+}
+
+/// Colors for each tile, by the character the tile is displayed as.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    default: Rgb,
+}
+
+impl Default for Palette {
+    /// Walls and rocks in white, everything else black.
+    fn default() -> Self {
+        Self::new(BLACK).with('#', WHITE)
+    }
+}
+
+impl Palette {
+    /// Palette drawing every tile with the `default` color.
+    pub fn new(default: Rgb) -> Self {
+        Self { colors: HashMap::new(), default }
+    }
+
+    pub fn with(mut self, tile: char, color: Rgb) -> Self {
+        self.colors.insert(tile, color);
+        self
+    }
+
+    pub fn color(&self, tile: char) -> Rgb {
+        self.colors.get(&tile).copied().unwrap_or(self.default)
+    }
+}
+
+/// RGB image of a grid, with a pixel per tile until scaled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Vec<Rgb>,
+    width: usize,
+    height: usize,
+    /// Point drawn at the top left pixel.
+    origin: Point2D,
+    scale: usize,
+}
+
+impl Image {
+    pub fn new(size: Point2D, background: Rgb) -> Self {
+        let (width, height) = (size.0.max(0) as usize, size.1.max(0) as usize);
+        Self {
+            pixels: vec![background; width * height],
+            width,
+            height,
+            origin: Point2D(0, 0),
+            scale: 1,
+        }
+    }
+
+    pub fn from_map<T: Copy + Into<char>>(map: &Map2D<T>, palette: &Palette) -> Self {
+        Self {
+            pixels: map.iter().map(|&tile| palette.color(tile.into())).collect(),
+            width: map.width(),
+            height: map.height(),
+            origin: Point2D(0, 0),
+            scale: 1,
+        }
+    }
+
+    /// Image just big enough for the given tiles, with any missing tile in `background`.
+    pub fn from_tiles(tiles: impl IntoIterator<Item = (Point2D, char)>, palette: &Palette, background: Rgb) -> Self {
+        let tiles: Vec<_> = tiles.into_iter().collect();
+        let Some(min) = tiles.iter().map(|&(p, _)| p).reduce(|a, b| a.min(b)) else {
+            return Self::new(Point2D(0, 0), background);
+        };
+        let max = tiles.iter().map(|&(p, _)| p).fold(min, |a, b| a.max(b));
+
+        let mut image = Self::new(max - min + Point2D(1, 1), background);
+        image.origin = min;
+        for (point, tile) in tiles {
+            image.set(point, palette.color(tile));
+        }
+        image
+    }
+
+    /// Image of a set of points, like the ones in a `HashSet<Point2D>`.
+    pub fn from_points(points: impl IntoIterator<Item = Point2D>, color: Rgb, background: Rgb) -> Self {
+        let palette = Palette::new(color);
+        Self::from_tiles(points.into_iter().map(|p| (p, '#')), &palette, background)
+    }
+
+    /// Draws every tile as a `scale` by `scale` square.
+    pub fn scaled(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// Inclusive min and max corners of the drawn tiles.
+    pub fn bounds(&self) -> (Point2D, Point2D) {
+        let size = Point2D(self.width as isize, self.height as isize);
+        (self.origin, self.origin + size - Point2D(1, 1))
+    }
+
+    /// Copy of the image covering the `min..=max` tiles instead, filling any new tile with `background`.
+    pub fn reframed(&self, min: Point2D, max: Point2D, background: Rgb) -> Self {
+        let mut image = Self::new(max - min + Point2D(1, 1), background);
+        image.origin = min;
+        image.scale = self.scale;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let point = self.origin + Point2D(x as isize, y as isize);
+                image.set(point, self.pixels[x + y * self.width]);
+            }
+        }
+        image
+    }
+
+    /// Size in pixels, after scaling.
+    pub fn size(&self) -> Point2D {
+        Point2D((self.width * self.scale) as isize, (self.height * self.scale) as isize)
+    }
+
+    pub fn get(&self, point: Point2D) -> Option<Rgb> {
+        self.get_index(point).map(|i| self.pixels[i])
+    }
+
+    /// Colors the tile at `point`, returning whether it was inside the image.
+    pub fn set(&mut self, point: Point2D, color: Rgb) -> bool {
+        match self.get_index(point) {
+            Some(index) => {
+                self.pixels[index] = color;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Draws a path over the image, ignoring the points outside of it.
+    pub fn overlay_path(&mut self, path: &[Point2D], color: Rgb) {
+        for &point in path {
+            self.set(point, color);
+        }
+    }
+
+    fn get_index(&self, point: Point2D) -> Option<usize> {
+        let Point2D(x, y) = point - self.origin;
+        (x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height)
+            .then(|| x as usize + y as usize * self.width)
+    }
+
+    /// Pixel rows top to bottom as packed RGB bytes, after scaling.
+    fn rgb_rows(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        (0..self.height)
+            .map(|y| {
+                self.pixels[(y * self.width)..((y + 1) * self.width)].iter()
+                    .flat_map(|pixel| std::iter::repeat_n(pixel, self.scale))
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .flat_map(|row| std::iter::repeat_n(row, self.scale))
+    }
+
+    /// Writes the unscaled pixels and where they are, for [`Image::read_raw`] to read back.
+    pub(crate) fn write_raw(&self, mut writer: impl Write) -> io::Result<()> {
+        for value in [self.origin.0 as i64, self.origin.1 as i64, self.width as i64, self.height as i64] {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        writer.write_all(&self.pixels.concat())
+    }
+
+    pub(crate) fn read_raw(mut reader: impl Read) -> io::Result<Self> {
+        let mut header = [0; 4];
+        for value in &mut header {
+            let mut bytes = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            *value = i64::from_le_bytes(bytes);
+        }
+        let [x, y, width, height] = header;
+        let mut bytes = vec![0; width as usize * height as usize * 3];
+        reader.read_exact(&mut bytes)?;
+
+        Ok(Self {
+            pixels: bytes.chunks_exact(3).map(|rgb| [rgb[0], rgb[1], rgb[2]]).collect(),
+            width: width as usize,
+            height: height as usize,
+            origin: Point2D(x as isize, y as isize),
+            scale: 1,
+        })
+    }
+
+    /// Writes the image as a binary PPM, which any image viewer can open.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        let size = self.size();
+        write!(writer, "P6\n{} {}\n255\n", size.0, size.1)?;
+        for row in self.rgb_rows() {
+            writer.write_all(&row)?;
+        }
+        writer.flush()
+    }
+
+    /// Packed RGB bytes row by row, after scaling.
+    pub fn to_rgb(&self) -> Vec<u8> {
+        self.rgb_rows().flatten().collect()
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> Result<(), png::EncodingError> {
+        let size = self.size();
+        let mut encoder = png::Encoder::new(writer, size.0 as u32, size.1 as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.to_rgb())
+    }
+
+    /// Saves the image as a PNG or PPM depending on the extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ImageError> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("png") => self.write_png(BufWriter::new(File::create(path)?))?,
+            Some("ppm") => self.write_ppm(BufWriter::new(File::create(path)?))?,
+            _ => return Err(ImageError::UnknownFormat(path.to_path_buf())),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn writes_scaled_ppm() {
+        let map = Map2D::<u8>::from_str("#.\n.#").unwrap();
+        let image = Image::from_map(&map, &Palette::default()).scaled(2);
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        let pixels: Vec<_> = ppm[header.len()..].chunks(3).map(|c| c[0]).collect();
+        assert_eq!(pixels, vec![
+            255, 255, 0, 0,
+            255, 255, 0, 0,
+            0, 0, 255, 255,
+            0, 0, 255, 255,
+        ]);
+    }
+
+    #[test]
+    fn places_points_and_path() {
+        let mut image = Image::from_points([Point2D(-2, 3), Point2D(1, 5)], WHITE, BLACK);
+        image.overlay_path(&[Point2D(-1, 4), Point2D(10, 10)], RED);
+
+        assert_eq!(image.size(), Point2D(4, 3));
+        assert_eq!(image.get(Point2D(-2, 3)), Some(WHITE));
+        assert_eq!(image.get(Point2D(-1, 4)), Some(RED));
+        assert_eq!(image.get(Point2D(0, 3)), Some(BLACK));
+        assert_eq!(image.get(Point2D(10, 10)), None);
+    }
+
+    #[test]
+    fn writes_png() {
+        let image = Image::new(Point2D(3, 2), RED).scaled(3);
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+
+        let decoder = png::Decoder::new(png.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (9, 6));
+    }
+}
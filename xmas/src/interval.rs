@@ -0,0 +1,245 @@
+use std::ops::RangeInclusive;
+
+/// Integers that can be stored in an [`IntervalSet`].
+pub trait Integer: Copy + Ord {
+    fn next(self) -> Option<Self>;
+    fn prev(self) -> Option<Self>;
+    /// Amount of values in `start..=end`, which must not be empty.
+    fn count(start: Self, end: Self) -> usize;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> usize {
+                end.abs_diff(start) as usize + 1
+            }
+        })*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Set of integers stored as sorted, disjoint and non-adjacent inclusive ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    /// Amount of values in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|&(start, end)| T::count(start, end)).sum()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(index).is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Ranges overlapping or right next to the new one get merged into it
+        let first = self.ranges.partition_point(|&(_, e)| e < start && e.next() != Some(start));
+        let last = self.ranges.partition_point(|&(s, _)| s <= end || end.next() == Some(s));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first >= last {
+            return;
+        }
+
+        // Only the ranges at both ends can stick out of the removed one
+        let mut leftovers = Vec::with_capacity(2);
+        let (first_start, _) = self.ranges[first];
+        let (_, last_end) = self.ranges[last - 1];
+        if let Some(before) = start.prev().filter(|&before| first_start <= before) {
+            leftovers.push((first_start, before));
+        }
+        if let Some(after) = end.next().filter(|&after| after <= last_end) {
+            leftovers.push((after, last_end));
+        }
+        self.ranges.splice(first..last, leftovers);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut lhs, mut rhs) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+
+        while let (Some(&&(l_start, l_end)), Some(&&(r_start, r_end))) = (lhs.peek(), rhs.peek()) {
+            let (start, end) = (l_start.max(r_start), l_end.min(r_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Whichever ends first can't overlap with anything else on the other side
+            if l_end < r_end {
+                lhs.next();
+            } else {
+                rhs.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Every value within `bounds` that isn't in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let mut complement = Self::from(bounds);
+        for range in self.iter() {
+            complement.remove(range);
+        }
+        complement
+    }
+
+    /// Smallest value within `bounds` that isn't in the set.
+    pub fn first_gap(&self, bounds: RangeInclusive<T>) -> Option<T> {
+        let (mut candidate, end) = bounds.into_inner();
+        let first = self.ranges.partition_point(|&(_, e)| e < candidate);
+        for &(start, range_end) in &self.ranges[first..] {
+            if candidate < start {
+                break;
+            }
+            candidate = range_end.next()?;
+        }
+        Some(candidate).filter(|&candidate| candidate <= end)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.iter().collect()
+    }
+
+    #[rstest]
+    #[case(vec![1..=3, 5..=7], vec![1..=3, 5..=7])]
+    #[case(vec![1..=3, 4..=7], vec![1..=7])]
+    #[case(vec![5..=7, 1..=3, 2..=6], vec![1..=7])]
+    #[case(vec![1..=2, 8..=9, 4..=5, 0..=10], vec![0..=10])]
+    #[case(vec![RangeInclusive::new(3, 1), 4..=4], vec![4..=4])]
+    fn merges_inserted_ranges(#[case] inserted: Vec<RangeInclusive<i32>>, #[case] expected: Vec<RangeInclusive<i32>>) {
+        let set: IntervalSet<_> = inserted.into_iter().collect();
+        assert_eq!(ranges(&set), expected);
+    }
+
+    #[rstest]
+    #[case(2..=3, vec![0..=1, 4..=10, 20..=30])]
+    #[case(0..=10, vec![20..=30])]
+    #[case(5..=25, vec![0..=4, 26..=30])]
+    #[case(11..=19, vec![0..=10, 20..=30])]
+    fn splits_removed_ranges(#[case] removed: RangeInclusive<i32>, #[case] expected: Vec<RangeInclusive<i32>>) {
+        let mut set = IntervalSet::from_iter([0..=10, 20..=30]);
+        set.remove(removed);
+        assert_eq!(ranges(&set), expected);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let lhs = IntervalSet::from_iter([0..=10, 20..=30]);
+        let rhs = IntervalSet::from_iter([5..=22, 28..=40]);
+
+        assert_eq!(ranges(&lhs.union(&rhs)), vec![0..=40]);
+        assert_eq!(ranges(&lhs.intersection(&rhs)), vec![5..=10, 20..=22, 28..=30]);
+        assert_eq!(ranges(&lhs.complement(-5..=25)), vec![-5..=-1, 11..=19]);
+    }
+
+    #[test]
+    fn counts_and_finds_values() {
+        let set = IntervalSet::from_iter([0..=10, 20..=30]);
+
+        assert_eq!(set.len(), 22);
+        assert!(set.contains(20));
+        assert!(!set.contains(15));
+        assert_eq!(set.first_gap(0..=30), Some(11));
+        assert_eq!(set.first_gap(20..=30), None);
+        assert_eq!(set.first_gap(-3..=30), Some(-3));
+    }
+
+    #[test]
+    fn handles_type_limits() {
+        let mut set = IntervalSet::from_iter([0..=u8::MAX]);
+        assert_eq!(set.len(), 256);
+        assert_eq!(set.first_gap(0..=u8::MAX), None);
+
+        set.remove(0..=0);
+        set.remove(u8::MAX..=u8::MAX);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=254]);
+    }
+}
@@ -1,10 +1,24 @@
+pub mod cycle;
 pub mod direction;
 pub mod direction3d;
+pub mod image;
+pub mod interval;
 pub mod map2d;
+pub mod point;
 pub mod point2d;
 pub mod point3d;
+pub mod record;
+pub mod region;
 pub mod result;
+pub mod rotation;
+pub mod search;
 pub mod keyed_ord;
 pub mod num;
+pub mod parse;
+pub mod solution;
+pub mod solver;
+pub mod sparse_grid;
+pub mod visualize;
+pub mod vm;
 
 pub use result::display_result;
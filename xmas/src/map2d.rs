@@ -1,18 +1,33 @@
 use core::str;
-use std::{fmt::Display, str::FromStr};
+use std::{collections::HashSet, fmt::Display, str::FromStr};
 use thiserror::Error;
 
-use crate::point2d::Point2D;
+use crate::{
+    direction::{Direction, QuarterRotation, DIRECTIONS, DIRECTIONS_8},
+    point2d::Point2D,
+    region::{self, Components, Connectivity},
+};
 
+/// Grid of tiles stored row by row, plain bytes unless another tile type is given.
+///
+/// Tiles are parsed from the bytes of each line with [`TryFrom<u8>`] and displayed with [`Into<char>`],
+/// so a tile enum only needs those two conversions to be read from and printed as a puzzle map.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Map2D {
-    map: Vec<u8>,
+pub struct Map2D<T = u8> {
+    map: Vec<T>,
     width: usize,
     height: usize,
 }
 
-impl Map2D {
-    pub fn new_filled(size: Point2D, tile: u8) -> Self {
+impl<T> Default for Map2D<T> {
+    /// Map without any tiles.
+    fn default() -> Self {
+        Self { map: Vec::new(), width: 0, height: 0 }
+    }
+}
+
+impl<T: Clone> Map2D<T> {
+    pub fn new_filled(size: Point2D, tile: T) -> Self {
         let width = size.0 as usize;
         let height = size.1 as usize;
         let map = vec![tile; width * height];
@@ -23,25 +38,106 @@ impl Map2D {
         }
     }
 
-    pub fn new_with_default_tiles(size: Point2D) -> Self {
+    pub fn new_with_default_tiles(size: Point2D) -> Self
+    where
+        T: Default,
+    {
         Self::new_filled(size, Default::default())
     }
 
+    /// Map turned a quarter at a time, [`QuarterRotation::Right`] being clockwise.
+    pub fn rotated(&self, rotation: QuarterRotation) -> Self {
+        let size = rotated_size(self.size(), rotation);
+        let inverse = match rotation {
+            QuarterRotation::Right => QuarterRotation::Left,
+            QuarterRotation::Left => QuarterRotation::Right,
+            other => other,
+        };
+        Self::from_fn(size, |point| self.tile_at(rotate_point(point, size, inverse)))
+    }
+
+    /// Map mirrored left to right.
+    pub fn flipped_horizontally(&self) -> Self {
+        let width = self.width as isize;
+        Self::from_fn(self.size(), |Point2D(x, y)| self.tile_at(Point2D(width - x - 1, y)))
+    }
+
+    /// Map mirrored top to bottom.
+    pub fn flipped_vertically(&self) -> Self {
+        let height = self.height as isize;
+        Self::from_fn(self.size(), |Point2D(x, y)| self.tile_at(Point2D(x, height - y - 1)))
+    }
+
+    /// Map mirrored along its top left to bottom right diagonal, so rows become columns.
+    pub fn transposed(&self) -> Self {
+        Self::from_fn(Point2D(self.size().1, self.size().0), |Point2D(x, y)| self.tile_at(Point2D(y, x)))
+    }
+
+    /// Copy of the `size` tiles starting at `origin`, if they're all inside the map.
+    pub fn sub_map(&self, origin: Point2D, size: Point2D) -> Option<Self> {
+        let inside = size.0 >= 0 && size.1 >= 0
+            && self.is_inside(origin)
+            && self.is_inside(origin + size - Point2D(1, 1));
+        inside.then(|| Self::from_fn(size, |point| self.tile_at(origin + point)))
+    }
+
+    /// Copies `source` over this map with its top left corner at `offset`, leaving the tiles
+    /// under `transparent` ones and anything falling outside the map untouched.
+    pub fn blit(&mut self, source: &Self, offset: Point2D, transparent: Option<&T>)
+    where
+        T: PartialEq,
+    {
+        for (point, tile) in source.iter_with_points() {
+            if transparent != Some(tile) {
+                self.set_tile(point + offset, tile.clone());
+            }
+        }
+    }
+
+    fn tile_at(&self, point: Point2D) -> T {
+        self.get_tile(point).unwrap().clone()
+    }
+}
+
+impl<T: TryFrom<u8>> Map2D<T> {
     pub fn parse_and_add_row(&mut self, line: &str) -> Result<(), ParseMapError> {
         if line.len() != self.width {
             return Err(ParseMapError::InconsistentRowSize { current: line.len(), expected: self.width });
         }
-        self.map.extend(line.bytes());
+
+        let y = self.height;
+        let row = line.bytes()
+            .enumerate()
+            .map(|(x, byte)| T::try_from(byte)
+                .map_err(|_| ParseMapError::InvalidTile { tile: char::from(byte), x, y }))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.map.extend(row);
         self.height += 1;
         Ok(())
     }
+}
 
+impl<T> Map2D<T> {
+    /// Map of the given size with each tile built from its point.
+    pub fn from_fn(size: Point2D, mut f: impl FnMut(Point2D) -> T) -> Self {
+        let (width, height) = (size.0.max(0) as usize, size.1.max(0) as usize);
+        let map = (0..height as isize)
+            .flat_map(|y| (0..width as isize).map(move |x| Point2D(x, y)))
+            .map(&mut f)
+            .collect();
+        Self { map, width, height }
+    }
+
+    /// Where the tile at `point` ends up once the map is [`rotated`](Self::rotated).
+    pub fn rotated_point(&self, point: Point2D, rotation: QuarterRotation) -> Point2D {
+        rotate_point(point, self.size(), rotation)
+    }
 
     pub fn is_inside(&self, point: Point2D) -> bool {
         point.0 >= 0 && point.1 >= 0 && (point.0 as usize) < self.width && (point.1 as usize) < self.height
     }
 
-    pub fn set_tile(&mut self, point: Point2D, tile: u8) -> bool {
+    pub fn set_tile(&mut self, point: Point2D, tile: T) -> bool {
         if let Some(index) = self.get_index(point) {
             self.map[index] = tile;
             true
@@ -50,11 +146,11 @@ impl Map2D {
         }
     }
 
-    pub fn get_tile(&self, point: Point2D) -> Option<&u8> {
+    pub fn get_tile(&self, point: Point2D) -> Option<&T> {
         self.get_index(point).and_then(|i| self.map.get(i))
     }
 
-    pub fn get_tile_mut(&mut self, point: Point2D) -> Option<&mut u8> {
+    pub fn get_tile_mut(&mut self, point: Point2D) -> Option<&mut T> {
         self.get_index(point).and_then(|i| self.map.get_mut(i))
     }
 
@@ -67,23 +163,81 @@ impl Map2D {
             .flat_map(|y| (0..(self.width as isize)).map(move |x| Point2D(x, y)))
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &u8> + '_ {
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
         self.map.iter()
     }
 
-    pub fn iter_with_points(&self) -> impl Iterator<Item = (Point2D, &u8)> + '_ {
+    pub fn iter_with_points(&self) -> impl Iterator<Item = (Point2D, &T)> + '_ {
         (0..(self.height as isize))
             .flat_map(|y| (0..(self.width as isize)).map(move |x| Point2D(x, y)))
             .map(|p| (p, self.get_tile(p).unwrap()))
     }
 
-    pub fn row(&self, index: usize) -> &[u8] {
+    /// Tiles right next to a point, up, down, left or right.
+    pub fn neighbors(&self, point: Point2D) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        DIRECTIONS.iter()
+            .map(move |dir| point + dir.as_point())
+            .filter_map(|neighbor| self.get_tile(neighbor).map(|tile| (neighbor, tile)))
+    }
+
+    /// Tiles around a point, diagonals included.
+    pub fn neighbors_8(&self, point: Point2D) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        DIRECTIONS_8.iter()
+            .map(move |&offset| point + offset)
+            .filter_map(|neighbor| self.get_tile(neighbor).map(|tile| (neighbor, tile)))
+    }
+
+    /// Tiles in a straight line from `from`, not included, up to the edge of the map.
+    pub fn ray(&self, from: Point2D, direction: Direction) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        let step = direction.as_point();
+        (1..)
+            .map(move |i| from + step * i)
+            .map_while(|point| self.get_tile(point).map(|tile| (point, tile)))
+    }
+
+    /// First point holding `tile`, row by row.
+    pub fn find(&self, tile: &T) -> Option<Point2D>
+    where
+        T: PartialEq,
+    {
+        self.find_all(tile).next()
+    }
+
+    /// Every point holding `tile`, row by row.
+    pub fn find_all<'a>(&'a self, tile: &'a T) -> impl Iterator<Item = Point2D> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter_with_points()
+            .filter(move |&(_, t)| t == tile)
+            .map(|(point, _)| point)
+    }
+
+    /// Every point reachable from `start` through `passable` tiles, empty if `start` isn't one.
+    pub fn flood_fill(&self, start: Point2D, connectivity: Connectivity, passable: impl Fn(&T) -> bool) -> HashSet<Point2D> {
+        if !self.get_tile(start).is_some_and(&passable) {
+            return HashSet::new();
+        }
+        region::flood_fill(start, |point| {
+            connectivity.neighbors(point).filter(|&p| self.get_tile(p).is_some_and(&passable))
+        })
+    }
+
+    /// Groups the `passable` tiles into connected regions.
+    pub fn components(&self, connectivity: Connectivity, passable: impl Fn(&T) -> bool) -> Components<Point2D> {
+        let points = self.iter_with_points()
+            .filter(|(_, tile)| passable(tile))
+            .map(|(point, _)| point);
+        region::label_components(points, |point| connectivity.neighbors(point))
+    }
+
+    pub fn row(&self, index: usize) -> &[T] {
         let start = index * self.width;
         let end = start + self.width;
         &self.map[start..end]
     }
 
-    pub fn rows_iter(&self) -> impl Iterator<Item = &[u8]> {
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
         (0..self.height).map(|y| self.row(y))
     }
 
@@ -98,6 +252,33 @@ impl Map2D {
     pub fn size(&self) -> Point2D {
         Point2D(self.width as isize, self.height as isize)
     }
+
+    /// Map of the same size with `f` applied to every tile.
+    pub fn map_tiles<U>(&self, f: impl FnMut(&T) -> U) -> Map2D<U> {
+        Map2D {
+            map: self.map.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+fn rotated_size(size: Point2D, rotation: QuarterRotation) -> Point2D {
+    match rotation {
+        QuarterRotation::None | QuarterRotation::TurnAround => size,
+        QuarterRotation::Right | QuarterRotation::Left => Point2D(size.1, size.0),
+    }
+}
+
+/// Turns `point` along with the `size` tiles around it.
+fn rotate_point(point: Point2D, size: Point2D, rotation: QuarterRotation) -> Point2D {
+    let Point2D(x, y) = point;
+    match rotation {
+        QuarterRotation::None => point,
+        QuarterRotation::Right => Point2D(size.1 - y - 1, x),
+        QuarterRotation::TurnAround => Point2D(size.0 - x - 1, size.1 - y - 1),
+        QuarterRotation::Left => Point2D(y, size.0 - x - 1),
+    }
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -106,9 +287,11 @@ pub enum ParseMapError {
     EmptyString,
     #[error("Inconsistent row size. Current: {current} Expected: {expected}")]
     InconsistentRowSize { current: usize, expected: usize },
+    #[error("Invalid tile '{tile}' at ({x}, {y})")]
+    InvalidTile { tile: char, x: usize, y: usize },
 }
 
-impl FromStr for Map2D {
+impl<T: TryFrom<u8>> FromStr for Map2D<T> {
     type Err = ParseMapError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -132,14 +315,11 @@ impl FromStr for Map2D {
     }
 }
 
-impl Display for Map2D {
+impl<T: Copy + Into<char>> Display for Map2D<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let lines = (0..self.height).map(|y| {
-            let from = y * self.width;
-            &self.map[from..(from + self.width)]
-        });
-        for line in lines {
-            writeln!(f, "{}", String::from_utf8_lossy(line))?;
+        for row in self.rows_iter() {
+            let line: String = row.iter().map(|&tile| tile.into()).collect();
+            writeln!(f, "{}", line)?;
         }
         Ok(())
     }
@@ -150,9 +330,36 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<u8> for Tile {
+        type Error = ();
+
+        fn try_from(byte: u8) -> Result<Self, Self::Error> {
+            match byte {
+                b'.' => Ok(Tile::Empty),
+                b'#' => Ok(Tile::Wall),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl From<Tile> for char {
+        fn from(tile: Tile) -> Self {
+            match tile {
+                Tile::Empty => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
     #[test]
     fn builds_map_correctly() {
-        let map = Map2D::new_with_default_tiles(Point2D(20, 10));
+        let map = Map2D::<u8>::new_with_default_tiles(Point2D(20, 10));
 
         assert_eq!(map.width, 20);
         assert_eq!(map.height, 10);
@@ -171,7 +378,7 @@ mod tests {
         #[case] point: Point2D,
         #[case] expected: Option<usize>,
     ) {
-        let map = Map2D::new_with_default_tiles(map_size);
+        let map = Map2D::<u8>::new_with_default_tiles(map_size);
         let index = map.get_index(point);
 
         assert_eq!(index, expected);
@@ -185,14 +392,14 @@ mod tests {
             "89AB\n",
         );
 
-        let map = Map2D::from_str(MAP).unwrap();
+        let map = Map2D::<u8>::from_str(MAP).unwrap();
         assert_eq!(map.width, 4);
         assert_eq!(map.height, 3);
     }
 
     #[test]
     fn parse_map_returns_empty_error() {
-        let result = Map2D::from_str("");
+        let result = Map2D::<u8>::from_str("");
         assert_eq!(result, Err(ParseMapError::EmptyString));
     }
 
@@ -204,7 +411,96 @@ mod tests {
             "89AB\n",
         );
 
-        let result = Map2D::from_str(MAP);
+        let result = Map2D::<u8>::from_str(MAP);
         assert_eq!(result, Err(ParseMapError::InconsistentRowSize { current: 3, expected: 4 }))
     }
+
+    #[test]
+    fn parses_and_displays_tiles() {
+        const MAP: &str = concat!(
+            "..#\n",
+            "#..\n",
+        );
+
+        let map = Map2D::<Tile>::from_str(MAP).unwrap();
+        assert_eq!(map.get_tile(Point2D(2, 0)), Some(&Tile::Wall));
+        assert_eq!(map.get_tile(Point2D(1, 1)), Some(&Tile::Empty));
+        assert_eq!(map.to_string(), MAP);
+    }
+
+    #[test]
+    fn parse_map_returns_invalid_tile_error() {
+        let result = Map2D::<Tile>::from_str("..#\n.x.\n");
+        assert_eq!(result, Err(ParseMapError::InvalidTile { tile: 'x', x: 1, y: 1 }));
+    }
+
+    #[rstest]
+    #[case(QuarterRotation::None, "abc\ndef\n")]
+    #[case(QuarterRotation::Right, "da\neb\nfc\n")]
+    #[case(QuarterRotation::TurnAround, "fed\ncba\n")]
+    #[case(QuarterRotation::Left, "cf\nbe\nad\n")]
+    fn rotates_map_and_points(#[case] rotation: QuarterRotation, #[case] expected: &str) {
+        let map = Map2D::<u8>::from_str("abc\ndef").unwrap();
+        let rotated = map.rotated(rotation);
+
+        assert_eq!(rotated.to_string(), expected);
+        for (point, tile) in map.iter_with_points() {
+            assert_eq!(rotated.get_tile(map.rotated_point(point, rotation)), Some(tile));
+        }
+    }
+
+    #[test]
+    fn flips_and_transposes() {
+        let map = Map2D::<u8>::from_str("abc\ndef").unwrap();
+
+        assert_eq!(map.flipped_horizontally().to_string(), "cba\nfed\n");
+        assert_eq!(map.flipped_vertically().to_string(), "def\nabc\n");
+        assert_eq!(map.transposed().to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn extracts_and_blits_sub_maps() {
+        let mut map = Map2D::<u8>::from_str("....\n....\n....").unwrap();
+        let stamp = Map2D::<u8>::from_str("#.\n##").unwrap();
+
+        map.blit(&stamp, Point2D(1, 0), Some(&b'.'));
+        map.blit(&stamp, Point2D(3, 2), None);
+        assert_eq!(map.to_string(), ".#..\n.##.\n...#\n");
+
+        assert_eq!(map.sub_map(Point2D(1, 0), Point2D(2, 2)), Some(stamp));
+        assert_eq!(map.sub_map(Point2D(3, 2), Point2D(2, 1)), None);
+    }
+
+    #[test]
+    fn finds_neighbors_within_bounds() {
+        let map = Map2D::<u8>::from_str("abc\ndef\nghi").unwrap();
+        let tiles = |neighbors: Vec<(Point2D, &u8)>| -> String {
+            let mut tiles: Vec<_> = neighbors.into_iter().map(|(_, &t)| char::from(t)).collect();
+            tiles.sort();
+            tiles.into_iter().collect()
+        };
+
+        assert_eq!(tiles(map.neighbors(Point2D(0, 0)).collect()), "bd");
+        assert_eq!(tiles(map.neighbors(Point2D(1, 1)).collect()), "bdfh");
+        assert_eq!(tiles(map.neighbors_8(Point2D(2, 1)).collect()), "bcehi");
+    }
+
+    #[rstest]
+    #[case(Point2D(0, 1), Direction::Right, vec![Point2D(1, 1), Point2D(2, 1)])]
+    #[case(Point2D(1, 1), Direction::Up, vec![Point2D(1, 0)])]
+    #[case(Point2D(2, 2), Direction::Down, vec![])]
+    fn casts_rays_to_the_edge(#[case] from: Point2D, #[case] direction: Direction, #[case] expected: Vec<Point2D>) {
+        let map = Map2D::<u8>::from_str("abc\ndef\nghi").unwrap();
+        let points: Vec<_> = map.ray(from, direction).map(|(p, _)| p).collect();
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn finds_tiles() {
+        let map = Map2D::<u8>::from_str("a.b\n.ba").unwrap();
+
+        assert_eq!(map.find(&b'b'), Some(Point2D(2, 0)));
+        assert_eq!(map.find(&b'x'), None);
+        assert_eq!(map.find_all(&b'a').collect::<Vec<_>>(), vec![Point2D(0, 0), Point2D(2, 1)]);
+    }
 }
@@ -0,0 +1,179 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+/// Error pointing at the exact part of the input that couldn't be parsed.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("Line {line}, column {column} ({text:?}): {message}")]
+pub struct ParseError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column within the line in characters, starting at 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+/// A line of the input along with its number, to report errors in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error about the whole line.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        self.error_at(self.text, message)
+    }
+
+    /// Error about `part`, which should be a slice of this line's text so its column can be found.
+    pub fn error_at(&self, part: &str, message: impl Display) -> ParseError {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = match self.text.get(..offset) {
+            Some(before) if offset + part.len() <= self.text.len() => before.chars().count() + 1,
+            _ => 1,
+        };
+        ParseError {
+            line: self.number,
+            column,
+            text: part.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Parses the whole line, ignoring surrounding whitespace.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+        where T: FromStr, T::Err: Display
+    {
+        self.parse_part(self.text.trim())
+    }
+
+    /// Parses `part`, a slice of this line's text.
+    pub fn parse_part<T>(&self, part: &str) -> Result<T, ParseError>
+        where T: FromStr, T::Err: Display
+    {
+        part.parse().map_err(|e| self.error_at(part, e))
+    }
+
+    /// Every integer in the line, see [`signed_ints`].
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+        where T: FromStr, T::Err: Display
+    {
+        signed_ints(self.text).map(|s| self.parse_part(s)).collect()
+    }
+
+    /// Splits the line in two around the first `separator`, trimming both sides.
+    pub fn key_value(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        key_value(self.text, separator)
+            .ok_or_else(|| self.error(format!("Expected key and value separated by {separator:?}")))
+    }
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines()
+        .enumerate()
+        .map(|(i, text)| Line { number: i + 1, text })
+}
+
+/// Parses every line of `input` into a `T`, failing at the first line that can't be parsed.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+    where T: FromStr, T::Err: Display
+{
+    lines(input).map(|line| line.parse()).collect()
+}
+
+/// Blocks of non blank lines separated by one or more blank lines.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = lines(input).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|l| l.text.trim().is_empty()).is_some() {}
+
+        let mut paragraph = Vec::new();
+        while let Some(line) = lines.next_if(|l| !l.text.trim().is_empty()) {
+            paragraph.push(line);
+        }
+        (!paragraph.is_empty()).then_some(paragraph)
+    })
+}
+
+/// Every run of digits in `s`, along with the `-` right before it unless that follows another
+/// digit, so `x=-2` gives `-2` but `4-6` gives `4` and `6`.
+pub fn signed_ints(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        let digits_start = pos + bytes[pos..].iter().position(u8::is_ascii_digit)?;
+        let digits_end = bytes[digits_start..].iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |len| digits_start + len);
+        pos = digits_end;
+
+        let negative = digits_start > 0
+            && bytes[digits_start - 1] == b'-'
+            && (digits_start < 2 || !bytes[digits_start - 2].is_ascii_digit());
+        let start = if negative { digits_start - 1 } else { digits_start };
+        Some(&s[start..digits_end])
+    })
+}
+
+/// Splits `s` in two around the first `separator`, trimming both sides.
+pub fn key_value<'a>(s: &'a str, separator: &str) -> Option<(&'a str, &'a str)> {
+    s.split_once(separator).map(|(key, value)| (key.trim(), value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    #[rstest]
+    #[case("Sensor at x=2, y=-18: beacon at x=-2", vec![2, -18, -2])]
+    #[case("2-4,6-8", vec![2, 4, 6, 8])]
+    #[case("-5 - -3", vec![-5, -3])]
+    #[case("no numbers", vec![])]
+    fn finds_signed_ints(#[case] s: &str, #[case] expected: Vec<i64>) {
+        let ints: Vec<i64> = signed_ints(s).map(|s| s.parse().unwrap()).collect();
+        assert_eq!(ints, expected);
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        let input = "\na\nb\n\n\nc\n  \nd\n";
+        let numbers: Vec<Vec<_>> = paragraphs(input)
+            .map(|p| p.iter().map(|l| l.number).collect())
+            .collect();
+
+        assert_eq!(numbers, vec![vec![2, 3], vec![6], vec![8]]);
+    }
+
+    #[test]
+    fn points_at_bad_line() {
+        let error = parse_lines::<i32>("1\n2\nthree\n4").unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "three");
+    }
+
+    #[test]
+    fn points_at_bad_column() {
+        let line = lines("ok\nx=1, y=99999999999").nth(1).unwrap();
+        let error = line.ints::<i32>().unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 8);
+        assert_eq!(error.text, "99999999999");
+    }
+
+    #[test]
+    fn splits_key_value() {
+        let line = lines("root: pppw + sjmn").next().unwrap();
+
+        assert_eq!(line.key_value(":"), Ok(("root", "pppw + sjmn")));
+        assert_eq!(line.key_value("=").unwrap_err().column, 1);
+    }
+}
@@ -0,0 +1,504 @@
+use std::{fmt::Display, num::ParseIntError, ops, str::FromStr};
+
+use thiserror::Error;
+
+use crate::direction::QuarterRotation;
+
+/// Point with `N` integer coordinates.
+///
+/// [`Point2D`](crate::point2d::Point2D) and [`Point3D`](crate::point3d::Point3D) wrap it so they
+/// keep their named tuple fields, and share all of its arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point<const N: usize>(pub [isize; N]);
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> Point<N> {
+    pub const ZERO: Self = Self([0; N]);
+
+    /// Point with every coordinate set to `value`.
+    pub fn splat(value: isize) -> Self {
+        Self([value; N])
+    }
+
+    pub fn map(&self, f: impl FnMut(isize) -> isize) -> Self {
+        Self(self.0.map(f))
+    }
+
+    /// Combines the coordinates of both points one by one.
+    pub fn zip_with(&self, other: Self, mut f: impl FnMut(isize, isize) -> isize) -> Self {
+        Self(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    /// Component-wise multiplication.
+    pub fn scale(&self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    /// Component-wise [`isize::div_euclid`].
+    pub fn div_euclid(&self, other: Self) -> Self {
+        self.zip_with(other, isize::div_euclid)
+    }
+
+    /// Component-wise [`isize::rem_euclid`], wrapping each coordinate into `0..other`.
+    pub fn rem_euclid(&self, other: Self) -> Self {
+        self.zip_with(other, isize::rem_euclid)
+    }
+
+    pub fn dot(&self, other: Self) -> isize {
+        self.scale(other).0.iter().sum()
+    }
+
+    pub fn abs(&self) -> Self {
+        self.map(isize::abs)
+    }
+
+    /// -1, 0 or 1 for each coordinate, a single step towards where this point is.
+    pub fn signum(&self) -> Self {
+        self.map(isize::signum)
+    }
+
+    pub fn max(&self, other: Self) -> Self {
+        self.zip_with(other, isize::max)
+    }
+
+    pub fn min(&self, other: Self) -> Self {
+        self.zip_with(other, isize::min)
+    }
+
+    pub fn manhattan_magnitude(&self) -> usize {
+        self.0.iter().map(|c| c.unsigned_abs()).sum()
+    }
+
+    pub fn manhattan_distance(&self, towards: Self) -> usize {
+        (*self - towards).manhattan_magnitude()
+    }
+
+    /// Largest coordinate, the amount of king moves to get here.
+    pub fn chebyshev_magnitude(&self) -> usize {
+        self.0.iter().map(|c| c.unsigned_abs()).max().unwrap_or(0)
+    }
+
+    pub fn chebyshev_distance(&self, towards: Self) -> usize {
+        (*self - towards).chebyshev_magnitude()
+    }
+
+    pub fn sqr_magnitude(&self) -> usize {
+        self.0.iter().map(|c| c * c).sum::<isize>() as usize
+    }
+
+    pub fn euclidean_magnitude(&self) -> f64 {
+        (self.sqr_magnitude() as f64).sqrt()
+    }
+
+    pub fn euclidean_distance(&self, towards: Self) -> f64 {
+        (*self - towards).euclidean_magnitude()
+    }
+}
+
+impl Point<2> {
+    /// Point turned a quarter at a time around the origin, [`QuarterRotation::Right`] being
+    /// clockwise with y going down, like [`Direction::turn_rotation`](crate::direction::Direction::turn_rotation).
+    pub fn rotated(&self, rotation: QuarterRotation) -> Self {
+        let [x, y] = self.0;
+        match rotation {
+            QuarterRotation::None => *self,
+            QuarterRotation::Right => Self([-y, x]),
+            QuarterRotation::TurnAround => Self([-x, -y]),
+            QuarterRotation::Left => Self([y, -x]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Point<3> {
+    pub fn cross(&self, other: Self) -> Self {
+        let ([ax, ay, az], [bx, by, bz]) = (self.0, other.0);
+        Self([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+
+    /// Point turned a quarter at a time around `axis`, which turns the other two coordinates like
+    /// [`Point::rotated`] in the order (y, z), (z, x) or (x, y).
+    pub fn rotated_around(&self, axis: Axis, rotation: QuarterRotation) -> Self {
+        let (a, b) = match axis {
+            Axis::X => (1, 2),
+            Axis::Y => (2, 0),
+            Axis::Z => (0, 1),
+        };
+        let [new_a, new_b] = Point([self.0[a], self.0[b]]).rotated(rotation).0;
+        let mut point = *self;
+        point.0[a] = new_a;
+        point.0[b] = new_b;
+        point
+    }
+}
+
+impl<const N: usize> Display for Point<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, coordinate) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{coordinate}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<const N: usize> ops::Add for Point<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize> ops::AddAssign for Point<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const N: usize> ops::Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize> ops::SubAssign for Point<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const N: usize> ops::Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|c| -c)
+    }
+}
+
+impl<const N: usize> ops::Mul<isize> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<const N: usize> ops::MulAssign<isize> for Point<N> {
+    fn mul_assign(&mut self, rhs: isize) {
+        *self = *self * rhs
+    }
+}
+
+/// Truncating division, like for `isize`.
+impl<const N: usize> ops::Div<isize> for Point<N> {
+    type Output = Self;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        self.map(|c| c / rhs)
+    }
+}
+
+impl<const N: usize> ops::DivAssign<isize> for Point<N> {
+    fn div_assign(&mut self, rhs: isize) {
+        *self = *self / rhs
+    }
+}
+
+impl<const N: usize> From<[isize; N]> for Point<N> {
+    fn from(value: [isize; N]) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> TryFrom<&[isize]> for Point<N> {
+    type Error = ParsePointError;
+
+    fn try_from(value: &[isize]) -> Result<Self, Self::Error> {
+        value.try_into()
+            .map(Self)
+            .map_err(|_| ParsePointError::InvalidLength { expected: N, found: value.len() })
+    }
+}
+
+impl<const N: usize> FromStr for Point<N> {
+    type Err = ParsePointError;
+
+    /// Comma separated coordinates, like `1,-2,3`, with or without spaces around them.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coordinates = s.split(',')
+            .map(|c| c.trim().parse())
+            .collect::<Result<Vec<isize>, _>>()?;
+        Self::try_from(coordinates.as_slice())
+    }
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ParsePointError {
+    #[error("Expected {expected} coordinates, found {found}")]
+    InvalidLength { expected: usize, found: usize },
+    #[error("Couldn't parse coordinate: {0}")]
+    ParseInt(#[from] ParseIntError),
+}
+
+/// Implements everything [`Point`] has for a tuple struct wrapping it, by converting back and
+/// forth, given the struct, its amount of coordinates and its fields.
+macro_rules! impl_point_wrapper {
+    ($name:ident, $n:literal, $($field:tt),+) => {
+        impl From<$crate::point::Point<$n>> for $name {
+            fn from(point: $crate::point::Point<$n>) -> Self {
+                Self($(point.0[$field]),+)
+            }
+        }
+
+        impl From<$name> for $crate::point::Point<$n> {
+            fn from(point: $name) -> Self {
+                Self([$(point.$field),+])
+            }
+        }
+
+        impl $name {
+            pub fn to_point(&self) -> $crate::point::Point<$n> {
+                (*self).into()
+            }
+
+            /// Point with every coordinate set to `value`.
+            pub fn splat(value: isize) -> Self {
+                $crate::point::Point::splat(value).into()
+            }
+
+            pub fn map(&self, f: impl FnMut(isize) -> isize) -> Self {
+                self.to_point().map(f).into()
+            }
+
+            /// Combines the coordinates of both points one by one.
+            pub fn zip_with(&self, other: Self, f: impl FnMut(isize, isize) -> isize) -> Self {
+                self.to_point().zip_with(other.into(), f).into()
+            }
+
+            /// Component-wise multiplication.
+            pub fn scale(&self, other: Self) -> Self {
+                self.to_point().scale(other.into()).into()
+            }
+
+            /// Component-wise [`isize::div_euclid`].
+            pub fn div_euclid(&self, other: Self) -> Self {
+                self.to_point().div_euclid(other.into()).into()
+            }
+
+            /// Component-wise [`isize::rem_euclid`], wrapping each coordinate into `0..other`.
+            pub fn rem_euclid(&self, other: Self) -> Self {
+                self.to_point().rem_euclid(other.into()).into()
+            }
+
+            pub fn dot(&self, other: Self) -> isize {
+                self.to_point().dot(other.into())
+            }
+
+            pub fn abs(&self) -> Self {
+                self.to_point().abs().into()
+            }
+
+            /// -1, 0 or 1 for each coordinate, a single step towards where this point is.
+            pub fn signum(&self) -> Self {
+                self.to_point().signum().into()
+            }
+
+            pub fn max(&self, other: Self) -> Self {
+                self.to_point().max(other.into()).into()
+            }
+
+            pub fn min(&self, other: Self) -> Self {
+                self.to_point().min(other.into()).into()
+            }
+
+            pub fn manhattan_magnitude(&self) -> usize {
+                self.to_point().manhattan_magnitude()
+            }
+
+            pub fn manhattan_distance(&self, towards: Self) -> usize {
+                self.to_point().manhattan_distance(towards.into())
+            }
+
+            /// Largest coordinate, the amount of king moves to get here.
+            pub fn chebyshev_magnitude(&self) -> usize {
+                self.to_point().chebyshev_magnitude()
+            }
+
+            pub fn chebyshev_distance(&self, towards: Self) -> usize {
+                self.to_point().chebyshev_distance(towards.into())
+            }
+
+            pub fn sqr_magnitude(&self) -> usize {
+                self.to_point().sqr_magnitude()
+            }
+
+            pub fn euclidean_magnitude(&self) -> f64 {
+                self.to_point().euclidean_magnitude()
+            }
+
+            pub fn euclidean_distance(&self, towards: Self) -> f64 {
+                self.to_point().euclidean_distance(towards.into())
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.to_point().fmt(f)
+            }
+        }
+
+        impl std::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                (self.to_point() + rhs.into()).into()
+            }
+        }
+
+        impl std::ops::AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs
+            }
+        }
+
+        impl std::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                (self.to_point() - rhs.into()).into()
+            }
+        }
+
+        impl std::ops::SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs
+            }
+        }
+
+        impl std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                (-self.to_point()).into()
+            }
+        }
+
+        impl std::ops::Mul<isize> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: isize) -> Self::Output {
+                (self.to_point() * rhs).into()
+            }
+        }
+
+        impl std::ops::MulAssign<isize> for $name {
+            fn mul_assign(&mut self, rhs: isize) {
+                *self = *self * rhs
+            }
+        }
+
+        /// Truncating division, like for `isize`.
+        impl std::ops::Div<isize> for $name {
+            type Output = Self;
+
+            fn div(self, rhs: isize) -> Self::Output {
+                (self.to_point() / rhs).into()
+            }
+        }
+
+        impl std::ops::DivAssign<isize> for $name {
+            fn div_assign(&mut self, rhs: isize) {
+                *self = *self / rhs
+            }
+        }
+
+        impl From<[isize; $n]> for $name {
+            fn from(value: [isize; $n]) -> Self {
+                $crate::point::Point(value).into()
+            }
+        }
+
+        impl TryFrom<&[isize]> for $name {
+            type Error = $crate::point::ParsePointError;
+
+            fn try_from(value: &[isize]) -> Result<Self, Self::Error> {
+                $crate::point::Point::<$n>::try_from(value).map(Self::from)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::point::ParsePointError;
+
+            /// Comma separated coordinates, like `1,-2`, with or without spaces around them.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse::<$crate::point::Point<$n>>().map(Self::from)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_point_wrapper;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    #[rstest]
+    #[case("1,2", Ok(Point([1, 2])))]
+    #[case(" -3 , 40 ", Ok(Point([-3, 40])))]
+    #[case("1,2,3", Err(ParsePointError::InvalidLength { expected: 2, found: 3 }))]
+    fn parses_points(#[case] s: &str, #[case] expected: Result<Point<2>, ParsePointError>) {
+        assert_eq!(s.parse(), expected);
+    }
+
+    #[test]
+    fn does_arithmetic() {
+        let point = Point([3, -4, 12]);
+
+        assert_eq!(-point + Point::splat(1), Point([-2, 5, -11]));
+        assert_eq!(point * 2 / 4, Point([1, -2, 6]));
+        assert_eq!(point.rem_euclid(Point::splat(5)), Point([3, 1, 2]));
+        assert_eq!(point.signum(), Point([1, -1, 1]));
+        assert_eq!(point.manhattan_magnitude(), 19);
+        assert_eq!(point.chebyshev_magnitude(), 12);
+        assert_eq!(point.euclidean_magnitude(), 13.0);
+        assert_eq!(point.to_string(), "(3, -4, 12)");
+    }
+
+    #[rstest]
+    #[case(QuarterRotation::None, Point([2, -1]))]
+    #[case(QuarterRotation::Right, Point([1, 2]))]
+    #[case(QuarterRotation::TurnAround, Point([-2, 1]))]
+    #[case(QuarterRotation::Left, Point([-1, -2]))]
+    fn rotates_2d(#[case] rotation: QuarterRotation, #[case] expected: Point<2>) {
+        assert_eq!(Point([2, -1]).rotated(rotation), expected);
+    }
+
+    #[rstest]
+    #[case(Axis::X, Point([1, -3, 2]))]
+    #[case(Axis::Y, Point([3, 2, -1]))]
+    #[case(Axis::Z, Point([-2, 1, 3]))]
+    fn rotates_3d_around_axes(#[case] axis: Axis, #[case] expected: Point<3>) {
+        let point = Point([1, 2, 3]);
+        assert_eq!(point.rotated_around(axis, QuarterRotation::Right), expected);
+        assert_eq!(expected.rotated_around(axis, QuarterRotation::Left), point);
+    }
+}
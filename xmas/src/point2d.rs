@@ -1,37 +1,18 @@
-use std::{fmt::Display, ops};
-use crate::direction::Direction;
+use crate::{direction::{Direction, QuarterRotation}, point::impl_point_wrapper, point3d::Point3D};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
 pub struct Point2D(pub isize, pub isize);
 
+impl_point_wrapper!(Point2D, 2, 0, 1);
+
 impl Point2D {
     pub const ZERO: Self = Point2D(0, 0);
 
-    pub fn manhattan_magnitude(&self) -> usize {
-        self.0.unsigned_abs() + self.1.unsigned_abs()
-    }
-
-    pub fn manhattan_distance(&self, towards: Point2D) -> usize {
-        (*self - towards).manhattan_magnitude()
-    }
-
-    pub fn sqr_magnitude(&self) -> usize {
-        (self.0 * self.0 + self.1 * self.1) as usize
-    }
-
-    pub fn max(&self, other: Self) -> Self {
-        Point2D(self.0.max(other.0), self.1.max(other.1))
-    }
-
-    pub fn min(&self, other: Self) -> Self {
-        Point2D(self.0.min(other.0), self.1.min(other.1))
-    }
-
     pub fn try_get_direction(&self) -> Option<(Direction, usize)> {
         match self {
             Point2D(0, 0) => None,
             Point2D(0, y) => Some((if *y > 0 { Direction::Down } else { Direction::Up }, y.unsigned_abs())),
-            Point2D(x, 0) => Some((if *x > 0 { Direction::Left } else { Direction::Right }, x.unsigned_abs())),
+            Point2D(x, 0) => Some((if *x > 0 { Direction::Right } else { Direction::Left }, x.unsigned_abs())),
             _ => None,
         }
     }
@@ -39,55 +20,15 @@ impl Point2D {
     pub fn try_get_direction_towards(&self, target: Point2D) -> Option<(Direction, usize)> {
         (target - *self).try_get_direction()
     }
-    
-    pub fn scale(&self, other: Point2D) -> Point2D {
-        Point2D(self.0 * other.0, self.1 * other.1)
-    }
 
-    pub fn map(&self, f: impl Fn(isize) -> isize) -> Self {
-        Self(f(self.0), f(self.1))
+    /// Point turned a quarter at a time around the origin, see [`Point::rotated`](crate::point::Point::rotated).
+    pub fn rotated(&self, rotation: QuarterRotation) -> Self {
+        self.to_point().rotated(rotation).into()
     }
-}
-
-impl Display for Point2D {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.0, self.1)
-    }
-}
-
-impl ops::Add<Point2D> for Point2D {
-    type Output = Point2D;
-
-    fn add(self, rhs: Point2D) -> Self::Output {
-        Point2D(self.0 + rhs.0, self.1 + rhs.1)
-    }
-}
-
-impl ops::AddAssign<Point2D> for Point2D {
-    fn add_assign(&mut self, rhs: Point2D) {
-        *self = *self + rhs
-    }
-}
-
-impl ops::Sub<Point2D> for Point2D {
-    type Output = Point2D;
-
-    fn sub(self, rhs: Point2D) -> Self::Output {
-        Point2D(self.0 - rhs.0, self.1 - rhs.1)
-    }
-}
-
-impl ops::SubAssign<Point2D> for Point2D {
-    fn sub_assign(&mut self, rhs: Point2D) {
-        *self = *self - rhs
-    }
-}
-
-impl ops::Mul<isize> for Point2D {
-    type Output = Self;
 
-    fn mul(self, rhs: isize) -> Self::Output {
-        Self(self.0 * rhs, self.1 * rhs)
+    /// The same point in 3D, at depth `z`.
+    pub fn with_z(&self, z: isize) -> Point3D {
+        Point3D(self.0, self.1, z)
     }
 }
 
@@ -97,8 +38,31 @@ impl From<(isize, isize)> for Point2D {
     }
 }
 
-impl From<[isize; 2]> for Point2D {
-    fn from(value: [isize; 2]) -> Self {
-        Self(value[0], value[1])
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Point2D(0, 0), None)]
+    #[case(Point2D(3, 0), Some((Direction::Right, 3)))]
+    #[case(Point2D(-2, 0), Some((Direction::Left, 2)))]
+    #[case(Point2D(0, 4), Some((Direction::Down, 4)))]
+    #[case(Point2D(0, -1), Some((Direction::Up, 1)))]
+    #[case(Point2D(1, 1), None)]
+    fn gets_straight_direction(#[case] point: Point2D, #[case] expected: Option<(Direction, usize)>) {
+        assert_eq!(point.try_get_direction(), expected);
+    }
+
+    #[test]
+    fn shares_point_arithmetic() {
+        let point: Point2D = "3, -4".parse().unwrap();
+
+        assert_eq!(point, Point2D(3, -4));
+        assert_eq!(-point * 2 / 3, Point2D(-2, 2));
+        assert_eq!(point.chebyshev_distance(Point2D(-1, -1)), 4);
+        assert_eq!(point.rotated(QuarterRotation::Right), Point2D(4, 3));
+        assert_eq!(point.with_z(5).xy(), point);
+        assert_eq!(point.to_string(), "(3, -4)");
     }
 }
@@ -0,0 +1,318 @@
+use std::{fs::{self, File}, io::{self, BufReader, BufWriter, Write}, path::PathBuf, sync::Mutex};
+
+use crate::{image::{Image, ImageError, Palette, Rgb, BLACK}, map2d::Map2D, point2d::Point2D};
+
+/// Settings for every recording in this process, `None` while recording is disabled.
+static SETTINGS: Mutex<Option<RecordSettings>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFormat {
+    /// A single animated `<name>.gif`.
+    Gif,
+    /// Numbered PNG frames in a `<name>` directory.
+    Frames,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordSettings {
+    /// Directory the recordings are written to.
+    pub dir: PathBuf,
+    pub format: RecordFormat,
+    /// Keeps one frame every this many steps.
+    pub every: usize,
+    /// Stops recording after this many steps, the simulation itself keeps going.
+    pub max_steps: Option<usize>,
+    /// Size in pixels of each tile.
+    pub scale: usize,
+    /// Time each frame of a GIF is shown, in hundredths of a second.
+    pub frame_delay: u16,
+}
+
+impl RecordSettings {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            format: RecordFormat::Gif,
+            every: 1,
+            max_steps: None,
+            scale: 4,
+            frame_delay: 5,
+        }
+    }
+}
+
+/// Records every simulation started from now on with `settings`.
+pub fn enable(settings: RecordSettings) {
+    *SETTINGS.lock().unwrap() = Some(settings);
+}
+
+pub fn disable() {
+    *SETTINGS.lock().unwrap() = None;
+}
+
+pub fn settings() -> Option<RecordSettings> {
+    SETTINGS.lock().unwrap().clone()
+}
+
+/// Frames kept in memory before they're moved to disk.
+const BUFFERED_FRAMES: usize = 16;
+
+/// Collects a frame of a simulation at each step, to write them out as an animation afterwards.
+///
+/// Only the last few frames are kept in memory, the others wait in a file next to the recording
+/// until it's written, so long simulations can be recorded whole.
+#[derive(Debug)]
+pub struct Recorder {
+    name: String,
+    settings: RecordSettings,
+    /// Frames not moved to disk yet.
+    frames: Vec<Image>,
+    /// Raw frames moved to disk so far, opened with the first of them.
+    spill: Option<BufWriter<File>>,
+    spilled: usize,
+    /// Bounds covering every frame so far, which they are all grown to when written.
+    bounds: Option<(Point2D, Point2D)>,
+    steps: usize,
+    /// First error moving frames to disk, after which no more frames are kept.
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    pub fn new(name: impl Into<String>, settings: RecordSettings) -> Self {
+        Self {
+            name: name.into(),
+            settings,
+            frames: Vec::new(),
+            spill: None,
+            spilled: 0,
+            bounds: None,
+            steps: 0,
+            error: None,
+        }
+    }
+
+    /// Recorder named `name` if recording was [`enable`]d.
+    pub fn if_enabled(name: impl Into<String>) -> Option<Self> {
+        settings().map(|settings| Self::new(name, settings))
+    }
+
+    /// Counts a step of the simulation, only drawing its frame if it's going to be kept.
+    pub fn step(&mut self, frame: impl FnOnce() -> Image) {
+        let step = self.steps;
+        self.steps += 1;
+
+        let within_limit = self.settings.max_steps.is_none_or(|max| step < max);
+        if !within_limit || !step.is_multiple_of(self.settings.every.max(1)) || self.error.is_some() {
+            return;
+        }
+
+        let frame = frame();
+        let (min, max) = frame.bounds();
+        self.bounds = Some(match self.bounds {
+            Some((min_all, max_all)) => (min_all.min(min), max_all.max(max)),
+            None => (min, max),
+        });
+        self.frames.push(frame);
+
+        if self.frames.len() >= BUFFERED_FRAMES {
+            if let Err(error) = self.spill_frames() {
+                self.error = Some(error);
+                self.frames.clear();
+            }
+        }
+    }
+
+    pub fn step_map<T: Copy + Into<char>>(&mut self, map: &Map2D<T>, palette: &Palette) {
+        self.step(|| Image::from_map(map, palette));
+    }
+
+    pub fn step_points(&mut self, points: impl IntoIterator<Item = Point2D>, color: Rgb) {
+        self.step(|| Image::from_points(points, color, BLACK));
+    }
+
+    /// Steps counted so far, including the ones without a frame.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The latest frames, the ones still in memory.
+    pub fn frames(&self) -> &[Image] {
+        &self.frames
+    }
+
+    /// Frames recorded so far, in memory or not.
+    pub fn frame_count(&self) -> usize {
+        self.spilled + self.frames.len()
+    }
+
+    fn spill_path(&self) -> PathBuf {
+        self.settings.dir.join(format!(".{}.frames", self.name))
+    }
+
+    /// Moves the frames in memory to the end of the spill file.
+    fn spill_frames(&mut self) -> io::Result<()> {
+        let spill = match &mut self.spill {
+            Some(spill) => spill,
+            None => {
+                fs::create_dir_all(&self.settings.dir)?;
+                self.spill.insert(BufWriter::new(File::create(self.spill_path())?))
+            },
+        };
+        for frame in self.frames.drain(..) {
+            frame.write_raw(&mut *spill)?;
+            self.spilled += 1;
+        }
+        Ok(())
+    }
+
+    /// Every frame, oldest first, scaled and grown to the same bounds so things stay in place as
+    /// the simulation grows. Frames are read back from disk one at a time.
+    fn aligned_frames(&mut self) -> io::Result<impl Iterator<Item = io::Result<Image>> + '_> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        let reader = match &mut self.spill {
+            Some(spill) => {
+                spill.flush()?;
+                Some(BufReader::new(File::open(self.spill_path())?))
+            },
+            None => None,
+        };
+
+        let spilled = self.spilled;
+        let from_disk = reader.into_iter()
+            .flat_map(move |mut reader| (0..spilled).map(move |_| Image::read_raw(&mut reader)));
+        let (min, max) = self.bounds.unwrap_or_default();
+        let scale = self.settings.scale;
+        Ok(from_disk
+            .chain(self.frames.iter().cloned().map(Ok))
+            .map(move |frame| frame.map(|frame| frame.reframed(min, max, BLACK).scaled(scale))))
+    }
+
+    /// Size in pixels of every written frame.
+    fn frame_size(&self) -> Option<Point2D> {
+        let (min, max) = self.bounds?;
+        Some((max - min + Point2D(1, 1)) * self.settings.scale.max(1) as isize)
+    }
+
+    pub fn write_gif(&mut self, writer: impl Write) -> Result<(), ImageError> {
+        let Some(size) = self.frame_size() else {
+            return Ok(());
+        };
+        let (Ok(width), Ok(height)) = (u16::try_from(size.0), u16::try_from(size.1)) else {
+            return Err(ImageError::TooLarge(size));
+        };
+
+        let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        let frame_delay = self.settings.frame_delay;
+        for frame in self.aligned_frames()? {
+            let mut gif_frame = gif::Frame::from_rgb_speed(width, height, &frame?.to_rgb(), 10);
+            gif_frame.delay = frame_delay;
+            encoder.write_frame(&gif_frame)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_frames(&mut self, dir: impl Into<PathBuf>) -> Result<(), ImageError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        for (i, frame) in self.aligned_frames()?.enumerate() {
+            frame?.save(dir.join(format!("frame_{i:05}.png")))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the recording in the configured directory and format, returning where it went.
+    pub fn finish(mut self) -> Result<PathBuf, ImageError> {
+        fs::create_dir_all(&self.settings.dir)?;
+        match self.settings.format {
+            RecordFormat::Gif => {
+                let path = self.settings.dir.join(format!("{}.gif", self.name));
+                let mut writer = BufWriter::new(File::create(&path)?);
+                self.write_gif(&mut writer)?;
+                writer.flush()?;
+                Ok(path)
+            },
+            RecordFormat::Frames => {
+                let path = self.settings.dir.join(&self.name);
+                self.write_frames(&path)?;
+                Ok(path)
+            },
+        }
+    }
+}
+
+impl Drop for Recorder {
+    /// Cleans up the frames moved to disk, finished or not.
+    fn drop(&mut self) {
+        if self.spill.take().is_some() {
+            // Nothing left to do about it if the file is gone already
+            let _ = fs::remove_file(self.spill_path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::WHITE;
+
+    fn recorder(every: usize, max_steps: Option<usize>) -> Recorder {
+        let settings = RecordSettings { every, max_steps, scale: 1, ..RecordSettings::new("unused") };
+        Recorder::new("test", settings)
+    }
+
+    #[test]
+    fn skips_frames_and_stops_at_limit() {
+        let mut recorder = recorder(3, Some(8));
+        for i in 0..20 {
+            recorder.step_points([Point2D(i, 0)], WHITE);
+        }
+
+        assert_eq!(recorder.steps(), 20);
+        let recorded: Vec<_> = recorder.frames().iter().map(|f| f.bounds().0).collect();
+        assert_eq!(recorded, vec![Point2D(0, 0), Point2D(3, 0), Point2D(6, 0)]);
+    }
+
+    #[test]
+    fn aligns_growing_frames() {
+        let mut recorder = recorder(1, None);
+        recorder.step_points([Point2D(0, 0)], WHITE);
+        recorder.step_points([Point2D(-1, 0), Point2D(2, 1)], WHITE);
+
+        let frames: Vec<_> = recorder.aligned_frames().unwrap().collect::<io::Result<_>>().unwrap();
+        assert!(frames.iter().all(|f| f.bounds() == (Point2D(-1, 0), Point2D(2, 1))));
+        assert_eq!(frames[0].get(Point2D(0, 0)), Some(WHITE));
+        assert_eq!(frames[0].get(Point2D(-1, 0)), Some(BLACK));
+
+        let mut gif = Vec::new();
+        recorder.write_gif(&mut gif).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+    }
+
+    #[test]
+    fn keeps_few_frames_in_memory() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = RecordSettings { scale: 1, ..RecordSettings::new(dir.path()) };
+        let mut recorder = Recorder::new("long", settings);
+        for i in 0..1000 {
+            recorder.step_points([Point2D(i % 50, i / 50)], WHITE);
+            assert!(recorder.frames().len() < BUFFERED_FRAMES);
+        }
+        assert_eq!(recorder.frame_count(), 1000);
+
+        // Frames come back from disk in order, grown to the bounds of all of them
+        let frames: Vec<_> = recorder.aligned_frames().unwrap().collect::<io::Result<_>>().unwrap();
+        assert_eq!(frames.len(), 1000);
+        assert!(frames.iter().all(|f| f.bounds() == (Point2D(0, 0), Point2D(49, 19))));
+        assert_eq!(frames[0].get(Point2D(0, 0)), Some(WHITE));
+        assert_eq!(frames[999].get(Point2D(49, 19)), Some(WHITE));
+        assert_eq!(frames[999].get(Point2D(0, 0)), Some(BLACK));
+
+        let spill = recorder.spill_path();
+        let gif = recorder.finish().unwrap();
+        assert!(gif.exists());
+        assert!(!spill.exists());
+    }
+}
@@ -0,0 +1,233 @@
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+use crate::{direction::{DIRECTIONS, DIRECTIONS_8}, direction3d::DIRECTIONS_3D, point2d::Point2D, point3d::Point3D};
+
+/// Points that regions can be made of, with a component-wise min and max for their bounding box.
+pub trait RegionPoint: Copy + Eq + Hash {
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+impl RegionPoint for Point2D {
+    fn min(self, other: Self) -> Self {
+        Point2D::min(&self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Point2D::max(&self, other)
+    }
+}
+
+impl RegionPoint for Point3D {
+    fn min(self, other: Self) -> Self {
+        Point3D::min(&self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Point3D::max(&self, other)
+    }
+}
+
+/// Offsets of [`DIRECTIONS`].
+const ORTHOGONAL: [Point2D; 4] = {
+    let mut offsets = [Point2D(0, 0); 4];
+    let mut i = 0;
+    while i < DIRECTIONS.len() {
+        offsets[i] = DIRECTIONS[i].as_point();
+        i += 1;
+    }
+    offsets
+};
+
+/// Which tiles of a grid touch each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right.
+    Four,
+    /// Diagonals included.
+    Eight,
+}
+
+impl Connectivity {
+    pub fn neighbors(self, point: Point2D) -> impl Iterator<Item = Point2D> {
+        let offsets: &[Point2D] = match self {
+            Self::Four => &ORTHOGONAL,
+            Self::Eight => &DIRECTIONS_8,
+        };
+        offsets.iter().map(move |&offset| point + offset)
+    }
+}
+
+/// The six points sharing a face with `point`.
+pub fn neighbors_3d(point: Point3D) -> impl Iterator<Item = Point3D> {
+    DIRECTIONS_3D.into_iter().map(move |dir| point + dir.as_point())
+}
+
+/// A connected group of points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<P> {
+    /// Amount of points in the region.
+    pub size: usize,
+    /// Inclusive corners of its bounding box.
+    pub min: P,
+    pub max: P,
+}
+
+/// Every connected region of a set of points, with the id of the region each point belongs to.
+#[derive(Debug, Clone)]
+pub struct Components<P> {
+    labels: HashMap<P, usize>,
+    regions: Vec<Region<P>>,
+}
+
+impl<P: RegionPoint> Components<P> {
+    /// Id of the region `point` belongs to, if any.
+    pub fn label(&self, point: P) -> Option<usize> {
+        self.labels.get(&point).copied()
+    }
+
+    /// The regions, indexed by their id in the order they were found.
+    pub fn regions(&self) -> &[Region<P>] {
+        &self.regions
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Points of the region with the given id.
+    pub fn points(&self, id: usize) -> impl Iterator<Item = P> + '_ {
+        self.labels.iter()
+            .filter(move |&(_, &label)| label == id)
+            .map(|(&point, _)| point)
+    }
+}
+
+/// Every point reachable from `start`, which is included, through `neighbors`.
+///
+/// `neighbors` should only return the points that can be walked into, and must stop returning
+/// new ones at some point for the fill to end.
+pub fn flood_fill<P, I>(start: P, mut neighbors: impl FnMut(P) -> I) -> HashSet<P>
+    where P: RegionPoint, I: IntoIterator<Item = P>
+{
+    let mut filled = HashSet::from([start]);
+    let mut pending = vec![start];
+    while let Some(point) = pending.pop() {
+        for neighbor in neighbors(point) {
+            if filled.insert(neighbor) {
+                pending.push(neighbor);
+            }
+        }
+    }
+    filled
+}
+
+/// Splits `points` into connected regions, where `neighbors` gives the points touching each one.
+/// Neighbors that aren't part of `points` are ignored.
+pub fn label_components<P, I>(points: impl IntoIterator<Item = P>, mut neighbors: impl FnMut(P) -> I) -> Components<P>
+    where P: RegionPoint, I: IntoIterator<Item = P>
+{
+    let points: Vec<_> = points.into_iter().collect();
+    let included: HashSet<_> = points.iter().copied().collect();
+
+    let mut labels = HashMap::new();
+    let mut regions = Vec::new();
+    for &start in &points {
+        if labels.contains_key(&start) {
+            continue;
+        }
+
+        let id = regions.len();
+        let region = flood_fill(start, |point| {
+            neighbors(point).into_iter().filter(|neighbor| included.contains(neighbor))
+        });
+        let mut min_max = (start, start);
+        for &point in &region {
+            labels.insert(point, id);
+            min_max = (min_max.0.min(point), min_max.1.max(point));
+        }
+        regions.push(Region { size: region.len(), min: min_max.0, max: min_max.1 });
+    }
+    Components { labels, regions }
+}
+
+/// Every point reachable from `start` through faces, staying on `passable` points.
+///
+/// `passable` must be false past some bounds for the fill to end.
+pub fn flood_fill_3d(start: Point3D, mut passable: impl FnMut(Point3D) -> bool) -> HashSet<Point3D> {
+    flood_fill(start, |point| neighbors_3d(point).filter(|&p| passable(p)).collect::<Vec<_>>())
+}
+
+/// Splits a set of voxels into regions connected through faces.
+pub fn components_3d(points: &HashSet<Point3D>) -> Components<Point3D> {
+    label_components(points.iter().copied(), neighbors_3d)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::map2d::Map2D;
+
+    const MAP: &str = concat!(
+        "##..#\n",
+        "#..#.\n",
+        "..#..\n",
+    );
+
+    #[test]
+    fn labels_grid_regions() {
+        let map = Map2D::<u8>::from_str(MAP).unwrap();
+
+        let four = map.components(Connectivity::Four, |&t| t == b'#');
+        assert_eq!(four.len(), 4);
+        let first = &four.regions()[four.label(Point2D(0, 1)).unwrap()];
+        assert_eq!(first, &Region { size: 3, min: Point2D(0, 0), max: Point2D(1, 1) });
+
+        let eight = map.components(Connectivity::Eight, |&t| t == b'#');
+        assert_eq!(eight.len(), 2);
+        assert_eq!(eight.label(Point2D(2, 2)), eight.label(Point2D(4, 0)));
+        assert_eq!(eight.points(eight.label(Point2D(3, 1)).unwrap()).count(), 3);
+    }
+
+    #[test]
+    fn fills_grid_from_a_point() {
+        let map = Map2D::<u8>::from_str(MAP).unwrap();
+
+        assert_eq!(map.flood_fill(Point2D(2, 0), Connectivity::Four, |&t| t == b'.').len(), 6);
+        assert_eq!(map.flood_fill(Point2D(2, 0), Connectivity::Eight, |&t| t == b'.').len(), 9);
+        assert!(map.flood_fill(Point2D(0, 0), Connectivity::Four, |&t| t == b'.').is_empty());
+    }
+
+    #[test]
+    fn labels_voxel_regions() {
+        let points = HashSet::from([
+            Point3D(0, 0, 0), Point3D(0, 0, 1), Point3D(0, 1, 1),
+            Point3D(5, 5, 5),
+            Point3D(1, 1, 0),
+        ]);
+        let components = components_3d(&points);
+
+        assert_eq!(components.len(), 3);
+        let mut sizes: Vec<_> = components.regions().iter().map(|r| r.size).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 3]);
+
+        let id = components.label(Point3D(0, 1, 1)).unwrap();
+        assert_eq!(components.regions()[id].max, Point3D(0, 1, 1));
+    }
+
+    #[test]
+    fn fills_bounded_voxels() {
+        let inside = |Point3D(x, y, z): Point3D| (-1..=4).contains(&x) && y.abs() <= 1 && z.abs() <= 1;
+        let filled = flood_fill_3d(Point3D(0, 0, 0), |p| inside(p) && p.0 != 2);
+
+        // Only the 3 by 3 by 3 box left of the wall at x = 2
+        assert_eq!(filled.len(), 27);
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use crate::{direction::{QuarterRotation, QUARTER_ROTATIONS}, direction3d::Direction3D, point::Axis, point3d::Point3D};
+
+/// One of the 24 ways to turn a cube in place, like the faces of a die.
+///
+/// Stored as where each axis ends up, so applying it to a point is a matrix product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CubeRotation {
+    /// Images of the x, y and z axes.
+    axes: [Point3D; 3],
+}
+
+impl Default for CubeRotation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl CubeRotation {
+    pub const IDENTITY: Self = Self { axes: [Point3D(1, 0, 0), Point3D(0, 1, 0), Point3D(0, 0, 1)] };
+
+    /// A quarter turn around `axis`, turning points like [`Point3D::rotated_around`].
+    pub fn quarter_turn(axis: Axis, rotation: QuarterRotation) -> Self {
+        Self { axes: Self::IDENTITY.axes.map(|p| p.rotated_around(axis, rotation)) }
+    }
+
+    /// The rotation taking [`Direction3D::Right`] to `right` and [`Direction3D::Down`] to `down`,
+    /// unless they aren't perpendicular.
+    pub fn from_axes(right: Direction3D, down: Direction3D) -> Option<Self> {
+        let (x, y) = (right.as_point(), down.as_point());
+        (x.dot(y) == 0).then(|| Self { axes: [x, y, x.cross(y)] })
+    }
+
+    /// Every rotation of the cube, starting with the identity.
+    pub fn all() -> Vec<Self> {
+        let generators = [
+            Self::quarter_turn(Axis::X, QuarterRotation::Right),
+            Self::quarter_turn(Axis::Y, QuarterRotation::Right),
+        ];
+
+        let mut found = HashSet::from([Self::IDENTITY]);
+        let mut rotations = vec![Self::IDENTITY];
+        let mut i = 0;
+        while let Some(&rotation) = rotations.get(i) {
+            for generator in generators {
+                let next = rotation.then(generator);
+                if found.insert(next) {
+                    rotations.push(next);
+                }
+            }
+            i += 1;
+        }
+        rotations
+    }
+
+    pub fn apply(&self, point: Point3D) -> Point3D {
+        let [x, y, z] = self.axes;
+        x * point.0 + y * point.1 + z * point.2
+    }
+
+    pub fn apply_direction(&self, direction: Direction3D) -> Direction3D {
+        Direction3D::try_from(self.apply(direction.as_point()))
+            .expect("Rotations keep unit vectors on the axes")
+    }
+
+    /// This rotation followed by `other`.
+    pub fn then(&self, other: Self) -> Self {
+        Self { axes: self.axes.map(|axis| other.apply(axis)) }
+    }
+
+    pub fn inverse(&self) -> Self {
+        let [x, y, z] = self.axes;
+        Self {
+            axes: [
+                Point3D(x.0, y.0, z.0),
+                Point3D(x.1, y.1, z.1),
+                Point3D(x.2, y.2, z.2),
+            ],
+        }
+    }
+
+    /// The turn on the front facing plane this is, if it keeps z in place.
+    pub fn to_quarter_rotation(&self) -> Option<QuarterRotation> {
+        if self.axes[2] != Point3D(0, 0, 1) {
+            return None;
+        }
+        let up = self.apply(Direction3D::Up.as_point());
+        QUARTER_ROTATIONS.into_iter()
+            .find(|&rotation| Direction3D::Up.as_point().rotated_around(Axis::Z, rotation) == up)
+    }
+}
+
+impl From<QuarterRotation> for CubeRotation {
+    /// The same turn on the front facing plane, around the z axis.
+    fn from(rotation: QuarterRotation) -> Self {
+        Self::quarter_turn(Axis::Z, rotation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{direction::DIRECTIONS, direction3d::DIRECTIONS_3D};
+
+    fn cube_points() -> impl Iterator<Item = Point3D> {
+        (-1..=1).flat_map(|x| (-1..=2).flat_map(move |y| (-3..=1).map(move |z| Point3D(x, y, z))))
+    }
+
+    #[test]
+    fn forms_a_group() {
+        let all = CubeRotation::all();
+        let set: HashSet<_> = all.iter().copied().collect();
+        assert_eq!(all.len(), 24);
+        assert_eq!(set.len(), 24);
+
+        for &a in &all {
+            assert_eq!(a.then(a.inverse()), CubeRotation::IDENTITY);
+            assert_eq!(a.inverse().then(a), CubeRotation::IDENTITY);
+            assert_eq!(a.axes[0].cross(a.axes[1]), a.axes[2], "{a:?} mirrors");
+            for &b in &all {
+                let ab = a.then(b);
+                assert!(set.contains(&ab));
+                for point in cube_points() {
+                    assert_eq!(ab.apply(point), b.apply(a.apply(point)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn applies_to_directions_like_points() {
+        for rotation in CubeRotation::all() {
+            for direction in DIRECTIONS_3D {
+                assert_eq!(rotation.apply_direction(direction).as_point(), rotation.apply(direction.as_point()));
+            }
+            let right = rotation.apply_direction(Direction3D::Right);
+            let down = rotation.apply_direction(Direction3D::Down);
+            assert_eq!(CubeRotation::from_axes(right, down), Some(rotation));
+        }
+        assert_eq!(CubeRotation::from_axes(Direction3D::Up, Direction3D::Down), None);
+    }
+
+    #[test]
+    fn turns_around_axes_like_points() {
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            for rotation in QUARTER_ROTATIONS {
+                let cube_rotation = CubeRotation::quarter_turn(axis, rotation);
+                for point in cube_points() {
+                    assert_eq!(cube_rotation.apply(point), point.rotated_around(axis, rotation));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_quarter_rotations_on_the_front() {
+        for rotation in QUARTER_ROTATIONS {
+            let cube_rotation = CubeRotation::from(rotation);
+            assert_eq!(cube_rotation.to_quarter_rotation(), Some(rotation));
+            for direction in DIRECTIONS {
+                let turned = Direction3D::from(direction.turn_rotation(rotation));
+                assert_eq!(cube_rotation.apply_direction(direction.into()), turned);
+            }
+        }
+        let tipped = CubeRotation::quarter_turn(Axis::X, QuarterRotation::Right);
+        assert_eq!(tipped.to_quarter_rotation(), None);
+    }
+}
@@ -0,0 +1,360 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use thiserror::Error;
+
+use crate::keyed_ord::KeyedOrd;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SearchError {
+    #[error("Every reachable node was expanded without reaching a goal")]
+    NotFound,
+    #[error("Gave up after expanding {0} nodes")]
+    LimitReached(usize),
+}
+
+/// A goal reached by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<N, C> {
+    pub goal: N,
+    pub cost: C,
+    /// Nodes from the start to the goal, both included, only if the search was asked to track it.
+    pub path: Option<Vec<N>>,
+    /// Nodes whose successors were generated before reaching the goal.
+    pub expanded: usize,
+}
+
+/// Settings shared by every kind of search.
+///
+/// Nodes are only expanded once per visited key, which defaults to the node itself with
+/// [`Search::by_node`] but can be any part of it that makes two nodes equivalent, like a
+/// position and a time modulo some period.
+#[derive(Debug, Clone, Copy)]
+pub struct Search<F> {
+    key: F,
+    max_expansions: Option<usize>,
+    track_path: bool,
+}
+
+impl<N: Clone> Search<fn(&N) -> N> {
+    pub fn by_node() -> Self {
+        Self::keyed_by(N::clone)
+    }
+}
+
+impl<F> Search<F> {
+    pub fn keyed_by(key: F) -> Self {
+        Self { key, max_expansions: None, track_path: false }
+    }
+
+    /// Fails with [`SearchError::LimitReached`] instead of expanding more than `max_expansions` nodes.
+    pub fn with_limit(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = Some(max_expansions);
+        self
+    }
+
+    /// Reconstructs the path to the goal into [`Found::path`].
+    pub fn with_path(mut self) -> Self {
+        self.track_path = true;
+        self
+    }
+
+    fn check_limit(&self, expanded: usize) -> Result<(), SearchError> {
+        match self.max_expansions {
+            Some(max) if expanded >= max => Err(SearchError::LimitReached(expanded)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Breadth first search, where every step costs 1.
+    pub fn bfs<N, K, I>(
+        &self,
+        start: N,
+        mut successors: impl FnMut(&N) -> I,
+        mut is_goal: impl FnMut(&N) -> bool,
+    ) -> Result<Found<N, usize>, SearchError>
+    where
+        F: Fn(&N) -> K,
+        K: Hash + Eq,
+        N: Clone,
+        I: IntoIterator<Item = N>,
+    {
+        let mut nodes = Nodes::new(self.track_path);
+        let mut visited = HashSet::from([(self.key)(&start)]);
+        let mut queue = VecDeque::from([nodes.push(start, None, 0)]);
+        let mut expanded = 0;
+
+        while let Some(index) = queue.pop_front() {
+            let (node, depth) = nodes.get(index);
+            if is_goal(node) {
+                return Ok(nodes.found(index, expanded));
+            }
+
+            self.check_limit(expanded)?;
+            expanded += 1;
+
+            for successor in successors(node) {
+                if visited.insert((self.key)(&successor)) {
+                    queue.push_back(nodes.push(successor, Some(index), depth + 1));
+                }
+            }
+        }
+        Err(SearchError::NotFound)
+    }
+
+    /// Lowest cost search for non-negative step costs.
+    pub fn dijkstra<N, K, C, I>(
+        &self,
+        start: N,
+        successors: impl FnMut(&N) -> I,
+        is_goal: impl FnMut(&N) -> bool,
+    ) -> Result<Found<N, C>, SearchError>
+    where
+        F: Fn(&N) -> K,
+        K: Hash + Eq,
+        N: Clone,
+        C: Copy + Ord + Default + Add<Output = C>,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        self.astar(start, successors, |_| C::default(), is_goal)
+    }
+
+    /// Lowest cost search guided by a `heuristic`, which must never overestimate the remaining cost.
+    pub fn astar<N, K, C, I>(
+        &self,
+        start: N,
+        mut successors: impl FnMut(&N) -> I,
+        mut heuristic: impl FnMut(&N) -> C,
+        mut is_goal: impl FnMut(&N) -> bool,
+    ) -> Result<Found<N, C>, SearchError>
+    where
+        F: Fn(&N) -> K,
+        K: Hash + Eq,
+        N: Clone,
+        C: Copy + Ord + Default + Add<Output = C>,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        let mut nodes = Nodes::new(self.track_path);
+        let mut closed = HashSet::new();
+        let mut open = BinaryHeap::new();
+        let estimate = heuristic(&start);
+        open.push(KeyedOrd::new(nodes.push(start, None, C::default()), Reverse(estimate)));
+        let mut expanded = 0;
+
+        while let Some(KeyedOrd { value: index, .. }) = open.pop() {
+            let (node, cost) = nodes.get(index);
+            if !closed.insert((self.key)(node)) {
+                continue;
+            }
+            if is_goal(node) {
+                return Ok(nodes.found(index, expanded));
+            }
+
+            self.check_limit(expanded)?;
+            expanded += 1;
+
+            for (successor, step_cost) in successors(node) {
+                if closed.contains(&(self.key)(&successor)) {
+                    continue;
+                }
+                let successor_cost = cost + step_cost;
+                let estimate = successor_cost + heuristic(&successor);
+                open.push(KeyedOrd::new(nodes.push(successor, Some(index), successor_cost), Reverse(estimate)));
+            }
+        }
+        Err(SearchError::NotFound)
+    }
+
+    /// Every node reachable from `start`, highest `priority` first, for searches that need to see
+    /// them all to find the best one. Stops early once the expansion limit is reached.
+    pub fn best_first<N, K, P, I, S, PF>(&self, start: N, successors: S, priority: PF) -> BestFirst<'_, F, N, K, P, S, PF>
+    where
+        F: Fn(&N) -> K,
+        K: Hash + Eq,
+        P: Ord,
+        I: IntoIterator<Item = N>,
+        S: FnMut(&N) -> I,
+        PF: FnMut(&N) -> P,
+    {
+        let mut best_first = BestFirst {
+            search: self,
+            open: BinaryHeap::new(),
+            visited: HashSet::new(),
+            successors,
+            priority,
+            expanded: 0,
+        };
+        best_first.push(start);
+        best_first
+    }
+}
+
+/// Every generated node with the index of its parent and its cost from the start.
+struct Nodes<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    track_path: bool,
+}
+
+impl<N: Clone, C: Copy> Nodes<N, C> {
+    fn new(track_path: bool) -> Self {
+        Self { nodes: Vec::new(), track_path }
+    }
+
+    fn push(&mut self, node: N, parent: Option<usize>, cost: C) -> usize {
+        self.nodes.push((node, parent, cost));
+        self.nodes.len() - 1
+    }
+
+    fn get(&self, index: usize) -> (&N, C) {
+        let (node, _, cost) = &self.nodes[index];
+        (node, *cost)
+    }
+
+    fn found(&self, index: usize, expanded: usize) -> Found<N, C> {
+        let (goal, cost) = self.get(index);
+        let path = self.track_path.then(|| {
+            let mut path: Vec<_> = std::iter::successors(Some(index), |&i| self.nodes[i].1)
+                .map(|i| self.nodes[i].0.clone())
+                .collect();
+            path.reverse();
+            path
+        });
+        Found { goal: goal.clone(), cost, path, expanded }
+    }
+}
+
+/// Iterator returned by [`Search::best_first`].
+pub struct BestFirst<'a, F, N, K, P, S, PF> {
+    search: &'a Search<F>,
+    open: BinaryHeap<KeyedOrd<N, P>>,
+    visited: HashSet<K>,
+    successors: S,
+    priority: PF,
+    expanded: usize,
+}
+
+impl<F, N, K, P, I, S, PF> BestFirst<'_, F, N, K, P, S, PF>
+where
+    F: Fn(&N) -> K,
+    K: Hash + Eq,
+    P: Ord,
+    I: IntoIterator<Item = N>,
+    S: FnMut(&N) -> I,
+    PF: FnMut(&N) -> P,
+{
+    fn push(&mut self, node: N) {
+        let priority = (self.priority)(&node);
+        self.open.push(KeyedOrd::new(node, priority));
+    }
+
+    /// Nodes whose successors were generated so far.
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+}
+
+impl<F, N, K, P, I, S, PF> Iterator for BestFirst<'_, F, N, K, P, S, PF>
+where
+    F: Fn(&N) -> K,
+    K: Hash + Eq,
+    P: Ord,
+    I: IntoIterator<Item = N>,
+    S: FnMut(&N) -> I,
+    PF: FnMut(&N) -> P,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let KeyedOrd { value: node, .. } = self.open.pop()?;
+            if !self.visited.insert((self.search.key)(&node)) {
+                continue;
+            }
+            if self.search.check_limit(self.expanded).is_err() {
+                return None;
+            }
+            self.expanded += 1;
+
+            for successor in (self.successors)(&node) {
+                if !self.visited.contains(&(self.search.key)(&successor)) {
+                    self.push(successor);
+                }
+            }
+            return Some(node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number line where each step moves 1 or 3 forward, the latter costing 5.
+    fn steps(&n: &i32) -> impl Iterator<Item = (i32, u32)> {
+        [(n + 1, 1), (n + 3, 5)].into_iter().filter(|&(n, _)| n <= 10)
+    }
+
+    #[test]
+    fn bfs_finds_fewest_steps() {
+        let found = Search::by_node()
+            .with_path()
+            .bfs(0, |n| steps(n).map(|(n, _)| n), |&n| n == 6)
+            .unwrap();
+
+        assert_eq!(found.cost, 2);
+        assert_eq!(found.path, Some(vec![0, 3, 6]));
+    }
+
+    #[test]
+    fn dijkstra_finds_lowest_cost() {
+        let found = Search::by_node().with_path().dijkstra(0, steps, |&n| n == 7).unwrap();
+
+        assert_eq!(found.cost, 7);
+        assert_eq!(found.path, Some((0..=7).collect()));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let dijkstra = Search::by_node().dijkstra(0, steps, |&n| n == 10).unwrap();
+        let astar = Search::by_node().astar(0, steps, |&n| (10 - n) as u32, |&n| n == 10).unwrap();
+
+        assert_eq!(astar.cost, dijkstra.cost);
+        assert!(astar.expanded <= dijkstra.expanded);
+        assert_eq!(astar.path, None);
+    }
+
+    #[test]
+    fn keys_visited_nodes() {
+        // Nodes are (position, time) but only the position matters
+        let found = Search::keyed_by(|&(n, _): &(i32, u32)| n)
+            .bfs((0, 0), |&(n, t)| [(n + 1, t + 1), (n, t + 1)], |&(n, _)| n == 4)
+            .unwrap();
+
+        assert_eq!(found.goal, (4, 4));
+        assert_eq!(found.expanded, 4);
+    }
+
+    #[test]
+    fn fails_without_goal() {
+        let result = Search::by_node().bfs(0, |n| steps(n).map(|(n, _)| n), |&n| n == 11);
+        assert_eq!(result, Err(SearchError::NotFound));
+    }
+
+    #[test]
+    fn stops_at_expansion_limit() {
+        let result = Search::by_node().with_limit(3).dijkstra(0, steps, |&n| n == 10);
+        assert_eq!(result, Err(SearchError::LimitReached(3)));
+    }
+
+    #[test]
+    fn best_first_visits_everything_by_priority() {
+        let search = Search::by_node();
+        let nodes: Vec<_> = search.best_first(0, |&n| [n * 2, n * 2 + 1].into_iter().filter(|&n| n < 8 && n > 0), |&n| n % 4).collect();
+
+        assert_eq!(nodes, vec![0, 1, 3, 7, 6, 2, 5, 4]);
+    }
+}
@@ -0,0 +1,107 @@
+use std::{fmt::Display, str::FromStr, time::{Duration, Instant}};
+
+use thiserror::Error;
+
+use crate::solver::{Params, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub const PARTS: [Part; 2] = [Part::One, Part::Two];
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Invalid part '{0}', expected 1 or 2")]
+pub struct ParsePartError(String);
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(ParsePartError(other.to_string())),
+        }
+    }
+}
+
+/// Type-erased access to a [`Solver`], so days can be picked at runtime.
+pub trait Solution {
+    /// Parses the input once with `params` and returns the displayed answer of each part, in order.
+    fn solve(&self, input: &str, params: &Params, parts: &[Part]) -> anyhow::Result<Vec<String>>;
+
+    /// Same as [`Solution::solve`], measuring the parsing and each part separately.
+    fn time(&self, input: &str, params: &Params, parts: &[Part]) -> anyhow::Result<Timings>;
+}
+
+/// Time spent by each step of a single run of a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    /// Time of each requested part, in order.
+    pub parts: Vec<Duration>,
+}
+
+impl<S: Solver> Solution for S {
+    fn solve(&self, input: &str, params: &Params, parts: &[Part]) -> anyhow::Result<Vec<String>> {
+        let parsed = S::parse_with(input, params)?;
+        parts.iter()
+            .map(|part| match part {
+                Part::One => S::part_1(&parsed).map(|answer| answer.to_string()),
+                Part::Two => S::part_2(&parsed).map(|answer| answer.to_string()),
+            })
+            .collect()
+    }
+
+    fn time(&self, input: &str, params: &Params, parts: &[Part]) -> anyhow::Result<Timings> {
+        let start = Instant::now();
+        let parsed = S::parse_with(input, params)?;
+        let parse = start.elapsed();
+
+        let parts = parts.iter()
+            .map(|part| {
+                let start = Instant::now();
+                // Answers are still displayed so formatting them counts like in a normal run
+                match part {
+                    Part::One => S::part_1(&parsed).map(|answer| answer.to_string()),
+                    Part::Two => S::part_2(&parsed).map(|answer| answer.to_string()),
+                }?;
+                Ok(start.elapsed())
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Timings { parse, parts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1", Ok(Part::One))]
+    #[case("2", Ok(Part::Two))]
+    #[case(" 2\n", Ok(Part::Two))]
+    #[case("3", Err(ParsePartError("3".to_string())))]
+    fn parses_part(#[case] s: &str, #[case] expected: Result<Part, ParsePartError>) {
+        assert_eq!(Part::from_str(s), expected);
+    }
+}
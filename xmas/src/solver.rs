@@ -0,0 +1,46 @@
+use std::{collections::HashMap, fmt::Display};
+
+/// A day's puzzle, parsing its input once and solving both parts from it.
+pub trait Solver {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    /// Parses an input along with numbers the puzzle gives in its text rather than in the input,
+    /// which the example often scales down. Days without any only implement [`Solver::parse`].
+    fn parse_with(input: &str, _params: &Params) -> anyhow::Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> anyhow::Result<Self::Answer1>;
+
+    fn part_2(input: &Self::Input) -> anyhow::Result<Self::Answer2>;
+}
+
+/// Named numbers a day is run with, see [`Solver::parse_with`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(HashMap<String, i64>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: &str, value: i64) -> Self {
+        self.0.insert(name.to_string(), value);
+        self
+    }
+
+    /// Value of `name`, or `default`, the one of the real puzzle, if it isn't set.
+    pub fn get_or(&self, name: &str, default: i64) -> i64 {
+        self.0.get(name).copied().unwrap_or(default)
+    }
+}
+
+impl FromIterator<(String, i64)> for Params {
+    fn from_iter<T: IntoIterator<Item = (String, i64)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
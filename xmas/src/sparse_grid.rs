@@ -0,0 +1,195 @@
+use std::{cell::Cell, collections::HashMap, fmt::Display};
+
+use crate::{direction::{DIRECTIONS, DIRECTIONS_8}, point2d::Point2D};
+
+/// Unbounded grid storing only the tiles that were set, for simulations that can grow in any direction.
+///
+/// The bounding box grows as tiles are set and is only recomputed when a tile on its edge is removed.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    tiles: HashMap<Point2D, T>,
+    /// Inclusive min and max corners, `None` while it needs to be recomputed.
+    bounds: Cell<Option<(Point2D, Point2D)>>,
+    empty: char,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            tiles: HashMap::new(),
+            bounds: Cell::new(None),
+            empty: '.',
+        }
+    }
+
+    /// Character displayed for the points with no tile, `.` by default.
+    pub fn with_empty_char(mut self, empty: char) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn contains(&self, point: Point2D) -> bool {
+        self.tiles.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point2D) -> Option<&T> {
+        self.tiles.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        self.tiles.get_mut(&point)
+    }
+
+    /// Sets a tile, returning the one that was there before.
+    pub fn set(&mut self, point: Point2D, tile: T) -> Option<T> {
+        if let Some((min, max)) = self.bounds.get() {
+            self.bounds.set(Some((min.min(point), max.max(point))));
+        } else if self.tiles.is_empty() {
+            self.bounds.set(Some((point, point)));
+        }
+        self.tiles.insert(point, tile)
+    }
+
+    pub fn remove(&mut self, point: Point2D) -> Option<T> {
+        let removed = self.tiles.remove(&point)?;
+        let on_edge = self.bounds.get().is_some_and(|(min, max)| {
+            point.0 == min.0 || point.0 == max.0 || point.1 == min.1 || point.1 == max.1
+        });
+        if on_edge {
+            self.bounds.set(None);
+        }
+        Some(removed)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        self.tiles.iter().map(|(&point, tile)| (point, tile))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        self.tiles.keys().copied()
+    }
+
+    /// Inclusive min and max corners of the tiles, `None` if there are none.
+    pub fn bounds(&self) -> Option<(Point2D, Point2D)> {
+        if self.bounds.get().is_none() {
+            let min = self.points().reduce(|a, b| a.min(b));
+            let max = self.points().reduce(|a, b| a.max(b));
+            self.bounds.set(min.zip(max));
+        }
+        self.bounds.get()
+    }
+
+    /// Width and height of the bounding box.
+    pub fn size(&self) -> Point2D {
+        self.bounds().map_or(Point2D::ZERO, |(min, max)| max - min + Point2D(1, 1))
+    }
+
+    /// Points inside the bounding box, tiles or not.
+    pub fn area(&self) -> usize {
+        let size = self.size();
+        (size.0 * size.1) as usize
+    }
+
+    /// Tiles right next to a point, up, down, left or right.
+    pub fn neighbors(&self, point: Point2D) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        DIRECTIONS.iter()
+            .map(move |dir| point + dir.as_point())
+            .filter_map(|neighbor| self.get(neighbor).map(|tile| (neighbor, tile)))
+    }
+
+    /// Tiles around a point, diagonals included.
+    pub fn neighbors_8(&self, point: Point2D) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        DIRECTIONS_8.iter()
+            .map(move |&offset| point + offset)
+            .filter_map(|neighbor| self.get(neighbor).map(|tile| (neighbor, tile)))
+    }
+}
+
+impl<T> FromIterator<(Point2D, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2D, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (point, tile) in iter {
+            grid.set(point, tile);
+        }
+        grid
+    }
+}
+
+impl<T: Copy + Into<char>> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.1..=max.1 {
+            let line: String = (min.0..=max.0)
+                .map(|x| self.get(Point2D(x, y)).map_or(self.empty, |&tile| tile.into()))
+                .collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(points: &[Point2D]) -> SparseGrid<char> {
+        points.iter().map(|&point| (point, '#')).collect()
+    }
+
+    #[test]
+    fn tracks_bounds() {
+        let mut grid = grid(&[Point2D(0, 0), Point2D(3, -2), Point2D(-1, 1)]);
+        assert_eq!(grid.bounds(), Some((Point2D(-1, -2), Point2D(3, 1))));
+        assert_eq!(grid.size(), Point2D(5, 4));
+
+        grid.remove(Point2D(3, -2));
+        assert_eq!(grid.bounds(), Some((Point2D(-1, 0), Point2D(0, 1))));
+
+        grid.set(Point2D(2, 2), '#');
+        assert_eq!(grid.bounds(), Some((Point2D(-1, 0), Point2D(2, 2))));
+        assert_eq!(grid.area(), 12);
+    }
+
+    #[test]
+    fn has_no_bounds_when_empty() {
+        let mut grid = grid(&[Point2D(4, 4)]);
+        grid.remove(Point2D(4, 4));
+
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.size(), Point2D::ZERO);
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        let grid = grid(&[Point2D(0, 0), Point2D(1, 0), Point2D(1, 1), Point2D(0, 2)]);
+
+        let mut neighbors: Vec<_> = grid.neighbors(Point2D(0, 1)).map(|(point, _)| point).collect();
+        neighbors.sort_by_key(|point| (point.1, point.0));
+        assert_eq!(neighbors, vec![Point2D(0, 0), Point2D(1, 1), Point2D(0, 2)]);
+        assert_eq!(grid.neighbors_8(Point2D(0, 1)).count(), 4);
+    }
+
+    #[test]
+    fn displays_bounding_box() {
+        let grid = grid(&[Point2D(5, 5), Point2D(7, 6)]).with_empty_char(' ');
+        assert_eq!(grid.to_string(), "#  \n  #\n");
+    }
+}
@@ -0,0 +1,343 @@
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, Write},
+    str::FromStr,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use thiserror::Error;
+
+use crate::{direction::Direction, map2d::Map2D, point2d::Point2D};
+
+/// Clears the terminal and moves the cursor to its top left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// A simulation that can be looked at one tick at a time.
+pub trait Simulation {
+    /// Advances the simulation by a tick, returning false once there's nothing left to do.
+    fn step(&mut self) -> bool;
+
+    /// Snapshot of the current state.
+    fn render(&self) -> Map2D<char>;
+}
+
+impl<S: Simulation + ?Sized> Simulation for Box<S> {
+    fn step(&mut self) -> bool {
+        (**self).step()
+    }
+
+    fn render(&self) -> Map2D<char> {
+        (**self).render()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    TogglePlay,
+    ZoomIn,
+    ZoomOut,
+    Scroll(Direction),
+    Quit,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Unknown command {0:?}")]
+pub struct ParseCommandError(String);
+
+impl FromStr for Command {
+    type Err = ParseCommandError;
+
+    /// An empty line steps once, a number steps that many ticks.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(ticks) = s.parse() {
+            return Ok(Self::Step(ticks));
+        }
+        match s {
+            "" | "n" => Ok(Self::Step(1)),
+            "p" => Ok(Self::TogglePlay),
+            "+" => Ok(Self::ZoomIn),
+            "-" => Ok(Self::ZoomOut),
+            "w" => Ok(Self::Scroll(Direction::Up)),
+            "a" => Ok(Self::Scroll(Direction::Left)),
+            "s" => Ok(Self::Scroll(Direction::Down)),
+            "d" => Ok(Self::Scroll(Direction::Right)),
+            "q" => Ok(Self::Quit),
+            _ => Err(ParseCommandError(s.to_string())),
+        }
+    }
+}
+
+/// Part of the simulation shown, each character covering `zoom` by `zoom` tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    /// Top left tile shown.
+    pub origin: Point2D,
+    /// Columns and rows of characters.
+    pub size: Point2D,
+    pub zoom: usize,
+}
+
+impl Viewport {
+    pub fn new(size: Point2D) -> Self {
+        Self { origin: Point2D(0, 0), size, zoom: 1 }
+    }
+
+    /// Draws the visible part of `map`, where each character is the first tile of its block that
+    /// isn't empty space, so zooming out doesn't hide things.
+    pub fn render(&self, map: &Map2D<char>) -> String {
+        let zoom = self.zoom as isize;
+        let mut output = String::new();
+        for row in 0..self.size.1 {
+            for column in 0..self.size.0 {
+                let block_origin = self.origin + Point2D(column, row) * zoom;
+                let mut block = (0..zoom)
+                    .flat_map(|y| (0..zoom).map(move |x| block_origin + Point2D(x, y)))
+                    .flat_map(|p| map.get_tile(p).copied());
+                let first = block.next();
+                let tile = first.into_iter()
+                    .chain(block)
+                    .find(|&c| c != '.' && c != ' ')
+                    .or(first)
+                    .unwrap_or(' ');
+                output.push(tile);
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Plays a [`Simulation`] inside a [`Viewport`], driven by [`Command`]s.
+pub struct Visualizer<S> {
+    simulation: S,
+    viewport: Viewport,
+    tick: usize,
+    finished: bool,
+    playing: bool,
+}
+
+impl<S: Simulation> Visualizer<S> {
+    pub fn new(simulation: S, viewport_size: Point2D) -> Self {
+        Self {
+            simulation,
+            viewport: Viewport::new(viewport_size),
+            tick: 0,
+            finished: false,
+            playing: false,
+        }
+    }
+
+    pub fn simulation(&self) -> &S {
+        &self.simulation
+    }
+
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    /// Advances the simulation unless it's finished already.
+    pub fn step(&mut self) {
+        if !self.finished {
+            self.finished = !self.simulation.step();
+            self.tick += 1;
+        }
+    }
+
+    /// Applies a command, returning false to quit. Steps are ignored while playing, only [p] pauses.
+    pub fn handle(&mut self, command: Command) -> bool {
+        let Point2D(width, height) = self.viewport.size;
+        let scroll_amount = Point2D((width / 4).max(1), (height / 4).max(1)) * self.viewport.zoom as isize;
+        match command {
+            Command::Step(_) if self.playing => {},
+            Command::Step(ticks) => {
+                for _ in 0..ticks {
+                    self.step();
+                }
+            },
+            Command::TogglePlay => self.playing = !self.playing && !self.finished,
+            Command::ZoomIn => self.viewport.zoom = (self.viewport.zoom - 1).max(1),
+            Command::ZoomOut => self.viewport.zoom += 1,
+            Command::Scroll(direction) => {
+                let Point2D(x, y) = direction.as_point();
+                self.viewport.origin += Point2D(x * scroll_amount.0, y * scroll_amount.1);
+            },
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    /// Status line followed by the visible part of the simulation.
+    pub fn render(&self) -> String {
+        let status = if self.finished {
+            "finished"
+        } else if self.playing {
+            "playing"
+        } else {
+            "paused"
+        };
+
+        let mut frame = String::new();
+        writeln!(
+            frame,
+            "Tick {} ({status}), zoom 1:{}, view at {}",
+            self.tick, self.viewport.zoom, self.viewport.origin,
+        ).unwrap();
+        frame.push_str(&self.viewport.render(&self.simulation.render()));
+        frame.push_str("[Enter] step, [N] step N, [p] play/pause, [+/-] zoom, [wasd] scroll, [q] quit\n");
+        frame
+    }
+
+    /// Draws a frame after every command and every tick while playing, until told to quit or
+    /// the commands run out.
+    pub fn run(&mut self, commands: Receiver<Command>, mut output: impl Write, tick_delay: Duration) -> io::Result<()> {
+        loop {
+            write!(output, "{CLEAR_SCREEN}{}", self.render())?;
+            output.flush()?;
+
+            let command = if self.playing {
+                match commands.recv_timeout(tick_delay) {
+                    Ok(command) => command,
+                    Err(RecvTimeoutError::Timeout) => {
+                        self.step();
+                        self.playing &= !self.finished;
+                        continue;
+                    },
+                    Err(RecvTimeoutError::Disconnected) => return Ok(()),
+                }
+            } else {
+                match commands.recv() {
+                    Ok(command) => command,
+                    Err(_) => return Ok(()),
+                }
+            };
+
+            if !self.handle(command) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Runs in the terminal, reading a command from each line of stdin.
+    pub fn run_interactive(&mut self, tick_delay: Duration) -> io::Result<()> {
+        let (sender, commands) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                // Typos are ignored, the help line is always on screen anyway
+                if let Ok(command) = Command::from_str(&line) {
+                    if sender.send(command).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        self.run(commands, io::stdout(), tick_delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    /// A dot moving right along a 10 wide track.
+    struct Track(isize);
+
+    impl Simulation for Track {
+        fn step(&mut self) -> bool {
+            self.0 += 1;
+            self.0 < 9
+        }
+
+        fn render(&self) -> Map2D<char> {
+            let mut map = Map2D::new_filled(Point2D(10, 2), '.');
+            map.set_tile(Point2D(self.0, 1), 'o');
+            map
+        }
+    }
+
+    #[rstest]
+    #[case("", Command::Step(1))]
+    #[case("12", Command::Step(12))]
+    #[case(" p ", Command::TogglePlay)]
+    #[case("a", Command::Scroll(Direction::Left))]
+    fn parses_commands(#[case] s: &str, #[case] expected: Command) {
+        assert_eq!(Command::from_str(s), Ok(expected));
+    }
+
+    #[test]
+    fn renders_headless() {
+        let mut visualizer = Visualizer::new(Track(0), Point2D(4, 2));
+        visualizer.handle(Command::Step(2));
+
+        assert_eq!(
+            visualizer.render(),
+            concat!(
+                "Tick 2 (paused), zoom 1:1, view at (0, 0)\n",
+                "....\n",
+                "..o.\n",
+                "[Enter] step, [N] step N, [p] play/pause, [+/-] zoom, [wasd] scroll, [q] quit\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn zooms_without_hiding_tiles() {
+        let mut visualizer = Visualizer::new(Track(5), Point2D(4, 1));
+        visualizer.handle(Command::ZoomOut);
+
+        // The second row is covered by the first one of characters
+        assert_eq!(visualizer.viewport().render(&visualizer.simulation().render()), "..o.\n");
+    }
+
+    #[test]
+    fn runs_commands_until_finished() {
+        let (sender, commands) = mpsc::channel();
+        for command in [Command::Step(3), Command::Scroll(Direction::Right), Command::Step(20)] {
+            sender.send(command).unwrap();
+        }
+        drop(sender);
+
+        let mut visualizer = Visualizer::new(Track(0), Point2D(4, 2));
+        let mut output = Vec::new();
+        visualizer.run(commands, &mut output, Duration::ZERO).unwrap();
+
+        assert_eq!(visualizer.tick(), 9);
+        assert_eq!(visualizer.viewport().origin, Point2D(1, 0));
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches(CLEAR_SCREEN).count(), 4);
+        assert!(output.ends_with(concat!(
+            "Tick 9 (finished), zoom 1:1, view at (1, 0)\n",
+            "....\n",
+            "....\n",
+            "[Enter] step, [N] step N, [p] play/pause, [+/-] zoom, [wasd] scroll, [q] quit\n",
+        )));
+    }
+
+    #[test]
+    fn only_pauses_on_toggle() {
+        let (sender, commands) = mpsc::channel();
+        for command in [Command::TogglePlay, Command::Step(1), Command::Step(5), Command::ZoomOut] {
+            sender.send(command).unwrap();
+        }
+        drop(sender);
+
+        let mut visualizer = Visualizer::new(Track(0), Point2D(4, 2));
+        visualizer.run(commands, io::sink(), Duration::from_secs(60)).unwrap();
+
+        assert_eq!(visualizer.tick(), 0);
+        assert!(visualizer.render().starts_with("Tick 0 (playing), zoom 1:2"));
+
+        visualizer.handle(Command::TogglePlay);
+        visualizer.handle(Command::Step(2));
+        assert_eq!(visualizer.tick(), 2);
+    }
+}
@@ -0,0 +1,427 @@
+use std::{cmp::Ordering, fmt::{self, Debug, Display}, io::{self, Write}, str::FromStr};
+
+use thiserror::Error;
+
+use crate::parse::{self, ParseError};
+
+/// Values of a machine's registers, each with a name for traces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registers {
+    names: Vec<&'static str>,
+    values: Vec<i64>,
+}
+
+impl Registers {
+    /// Registers named `names`, indexed in that order, all starting at 0.
+    pub fn new(names: &[&'static str]) -> Self {
+        Self { names: names.to_vec(), values: vec![0; names.len()] }
+    }
+
+    pub fn with(mut self, register: usize, value: i64) -> Self {
+        self.set(register, value);
+        self
+    }
+
+    pub fn get(&self, register: usize) -> i64 {
+        self.values[register]
+    }
+
+    pub fn set(&mut self, register: usize, value: i64) {
+        self.values[register] = value;
+    }
+
+    pub fn add(&mut self, register: usize, amount: i64) {
+        self.values[register] += amount;
+    }
+
+    /// Index of the register called `name`.
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|&n| n == name)
+    }
+
+    pub fn by_name(&self, name: &str) -> Option<i64> {
+        self.index(name).map(|register| self.values[register])
+    }
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.names.iter().zip(&self.values).enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Where to go once an instruction is done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Next,
+    /// Jump by this many instructions, relative to the one just run.
+    Jump(isize),
+    Halt,
+}
+
+/// An instruction a [`Machine`] can run.
+pub trait Instruction: Debug {
+    /// Cycles the instruction takes, its effect only showing after the last one.
+    fn cycles(&self) -> u64;
+
+    fn execute(&self, registers: &mut Registers) -> Flow;
+}
+
+/// Builds an instruction from the operands following its mnemonic.
+pub type Decoder<I> = fn(&[&str]) -> anyhow::Result<I>;
+
+#[derive(Debug, Error)]
+pub enum ParseInstructionError {
+    #[error("Empty instruction")]
+    Empty,
+    #[error("Unknown instruction {0:?}")]
+    Unknown(String),
+    #[error("Invalid operands in {line:?}")]
+    Operands {
+        line: String,
+        #[source]
+        source: anyhow::Error,
+    },
+}
+
+/// Table of the mnemonics of an instruction set and how to decode each of them.
+#[derive(Debug)]
+pub struct InstructionSet<I: 'static> {
+    decoders: &'static [(&'static str, Decoder<I>)],
+}
+
+impl<I> InstructionSet<I> {
+    pub const fn new(decoders: &'static [(&'static str, Decoder<I>)]) -> Self {
+        Self { decoders }
+    }
+
+    /// Decodes a line like `addx -3`, a mnemonic followed by operands split by whitespace.
+    pub fn decode(&self, line: &str) -> Result<I, ParseInstructionError> {
+        let mut words = line.split_whitespace();
+        let mnemonic = words.next().ok_or(ParseInstructionError::Empty)?;
+        let (_, decoder) = self.decoders.iter()
+            .find(|(name, _)| *name == mnemonic)
+            .ok_or_else(|| ParseInstructionError::Unknown(mnemonic.to_string()))?;
+
+        let operands: Vec<_> = words.collect();
+        decoder(&operands).map_err(|source| ParseInstructionError::Operands { line: line.to_string(), source })
+    }
+
+    /// Decodes an instruction on each line, like [`parse::parse_lines`] does with `FromStr`.
+    pub fn decode_program(&self, input: &str) -> Result<Vec<I>, ParseError> {
+        parse::lines(input)
+            .map(|line| self.decode(line.text).map_err(|e| line.error(e)))
+            .collect()
+    }
+}
+
+/// Parses operand `index` of `operands`, for [`Decoder`]s.
+pub fn operand<T>(operands: &[&str], index: usize) -> anyhow::Result<T>
+    where T: FromStr, T::Err: std::error::Error + Send + Sync + 'static
+{
+    let operand = operands.get(index)
+        .ok_or_else(|| anyhow::anyhow!("Missing operand {}", index + 1))?;
+    Ok(operand.parse()?)
+}
+
+/// Something watching the machine, told about every cycle while it's running.
+pub trait Peripheral {
+    /// Called during each cycle, before the instruction being run finishes.
+    fn tick(&mut self, cycle: u64, registers: &Registers);
+}
+
+impl Peripheral for () {
+    fn tick(&mut self, _cycle: u64, _registers: &Registers) {}
+}
+
+impl<A: Peripheral, B: Peripheral> Peripheral for (A, B) {
+    fn tick(&mut self, cycle: u64, registers: &Registers) {
+        self.0.tick(cycle, registers);
+        self.1.tick(cycle, registers);
+    }
+}
+
+impl<P: Peripheral + ?Sized> Peripheral for &mut P {
+    fn tick(&mut self, cycle: u64, registers: &Registers) {
+        (**self).tick(cycle, registers);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Stops once this cycle is over.
+    Cycle(u64),
+    /// Stops when comparing a register to `value` starts giving `ordering`.
+    Register { register: usize, ordering: Ordering, value: i64 },
+}
+
+impl Breakpoint {
+    fn holds(&self, cycle: u64, registers: &Registers) -> bool {
+        match *self {
+            Self::Cycle(at) => cycle == at,
+            Self::Register { register, ordering, value } => registers.get(register).cmp(&value) == ordering,
+        }
+    }
+}
+
+/// Why a [`Machine`] stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The program jumped out of itself, ran past its end or halted.
+    Halted,
+    /// Index of the breakpoint that was hit.
+    Breakpoint(usize),
+}
+
+/// Runs a program of [`Instruction`]s cycle by cycle.
+pub struct Machine<I> {
+    program: Vec<I>,
+    registers: Registers,
+    /// Index of the instruction being run.
+    pc: usize,
+    cycle: u64,
+    /// Cycles left of the instruction being run, if it was started.
+    remaining: Option<u64>,
+    halted: bool,
+    breakpoints: Vec<Breakpoint>,
+    trace: Option<Box<dyn Write>>,
+}
+
+impl<I: Instruction> Machine<I> {
+    pub fn new(program: Vec<I>, registers: Registers) -> Self {
+        let halted = program.is_empty();
+        Self {
+            program,
+            registers,
+            pc: 0,
+            cycle: 0,
+            remaining: None,
+            halted,
+            breakpoints: Vec::new(),
+            trace: None,
+        }
+    }
+
+    /// Adds a breakpoint, returning its index.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> usize {
+        self.breakpoints.push(breakpoint);
+        self.breakpoints.len() - 1
+    }
+
+    /// Writes a line to `writer` for every instruction run, with the registers it left.
+    pub fn trace_to(&mut self, writer: impl Write + 'static) {
+        self.trace = Some(Box::new(writer));
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Cycles run so far.
+    pub fn cycle(&self) -> u64 {
+        self.cycle
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Runs a single cycle, telling `peripheral` about it, unless the machine halted already.
+    pub fn step(&mut self, mut peripheral: impl Peripheral) -> io::Result<Option<Stop>> {
+        if self.halted {
+            return Ok(Some(Stop::Halted));
+        }
+
+        let held: Vec<_> = self.breakpoints.iter().map(|b| b.holds(self.cycle, &self.registers)).collect();
+        let instruction = &self.program[self.pc];
+        let remaining = self.remaining.get_or_insert(instruction.cycles().max(1));
+        self.cycle += 1;
+        peripheral.tick(self.cycle, &self.registers);
+
+        *remaining -= 1;
+        if *remaining == 0 {
+            self.remaining = None;
+            let flow = instruction.execute(&mut self.registers);
+            if let Some(trace) = &mut self.trace {
+                writeln!(trace, "{:>6} {:>4} {instruction:?}: {}", self.cycle, self.pc, self.registers)?;
+            }
+
+            let next = match flow {
+                Flow::Next => self.pc.checked_add(1),
+                Flow::Jump(offset) => self.pc.checked_add_signed(offset),
+                Flow::Halt => None,
+            };
+            match next {
+                Some(pc) if pc < self.program.len() => self.pc = pc,
+                _ => self.halted = true,
+            }
+        }
+
+        let hit = self.breakpoints.iter()
+            .zip(held)
+            .position(|(breakpoint, held)| !held && breakpoint.holds(self.cycle, &self.registers));
+        Ok(match hit {
+            Some(index) => Some(Stop::Breakpoint(index)),
+            None if self.halted => Some(Stop::Halted),
+            None => None,
+        })
+    }
+
+    /// Runs until the program halts or a breakpoint is hit, running again continuing after it.
+    pub fn run(&mut self, mut peripheral: impl Peripheral) -> io::Result<Stop> {
+        loop {
+            if let Some(stop) = self.step(&mut peripheral)? {
+                return Ok(stop);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use rstest::rstest;
+    use super::*;
+
+    const A: usize = 0;
+    const B: usize = 1;
+
+    #[derive(Debug)]
+    enum Op {
+        Add(usize, usize),
+        Inc(usize, i64),
+        Jnz(usize, isize),
+    }
+
+    impl Instruction for Op {
+        fn cycles(&self) -> u64 {
+            match self {
+                Op::Add(..) => 2,
+                Op::Inc(..) | Op::Jnz(..) => 1,
+            }
+        }
+
+        fn execute(&self, registers: &mut Registers) -> Flow {
+            match *self {
+                Op::Add(to, from) => registers.add(to, registers.get(from)),
+                Op::Inc(register, amount) => registers.add(register, amount),
+                Op::Jnz(register, offset) if registers.get(register) != 0 => return Flow::Jump(offset),
+                Op::Jnz(..) => {},
+            }
+            Flow::Next
+        }
+    }
+
+    fn register(name: &str) -> anyhow::Result<usize> {
+        Registers::new(&["a", "b"]).index(name).ok_or_else(|| anyhow::anyhow!("No register {name}"))
+    }
+
+    const OPS: InstructionSet<Op> = InstructionSet::new(&[
+        ("add", |ops| Ok(Op::Add(register(ops[0])?, register(ops[1])?))),
+        ("inc", |ops| Ok(Op::Inc(register(ops[0])?, operand(ops, 1)?))),
+        ("jnz", |ops| Ok(Op::Jnz(register(ops[0])?, operand(ops, 1)?))),
+    ]);
+
+    /// Counts `b` down to zero, adding it to `a` each time.
+    const PROGRAM: &str = "add a b\ninc b -1\njnz b -2\n";
+
+    fn machine() -> Machine<Op> {
+        let program = OPS.decode_program(PROGRAM).unwrap();
+        Machine::new(program, Registers::new(&["a", "b"]).with(B, 3))
+    }
+
+    /// Writer that can still be read once the machine owns it.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[rstest]
+    #[case("", ParseInstructionError::Empty)]
+    #[case("mul a b", ParseInstructionError::Unknown("mul".into()))]
+    fn rejects_bad_instructions(#[case] line: &str, #[case] expected: ParseInstructionError) {
+        assert_eq!(OPS.decode(line).unwrap_err().to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn reports_bad_operands() {
+        assert!(matches!(OPS.decode("inc a x"), Err(ParseInstructionError::Operands { .. })));
+        assert!(matches!(OPS.decode("jnz c 1"), Err(ParseInstructionError::Operands { .. })));
+    }
+
+    #[test]
+    fn reports_the_line_of_bad_instructions() {
+        let error = OPS.decode_program("add a b\n\nmul a b\n").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn runs_to_the_end_of_the_program() {
+        let mut machine = machine();
+        let mut seen = Vec::new();
+        struct Watch<'a>(&'a mut Vec<(u64, i64)>);
+        impl Peripheral for Watch<'_> {
+            fn tick(&mut self, cycle: u64, registers: &Registers) {
+                self.0.push((cycle, registers.get(A)));
+            }
+        }
+
+        assert_eq!(machine.run(Watch(&mut seen)).unwrap(), Stop::Halted);
+        // The last instruction counts too, 3 loops of 4 cycles each
+        assert!(machine.is_halted());
+        assert_eq!(machine.cycle(), 12);
+        assert_eq!(machine.registers().get(A), 3 + 2 + 1);
+        assert_eq!(machine.registers().to_string(), "a=6 b=0");
+        // Additions only show after their second cycle
+        assert_eq!(&seen[..3], &[(1, 0), (2, 0), (3, 3)]);
+        assert_eq!(machine.step(()).unwrap(), Some(Stop::Halted));
+        assert_eq!(machine.cycle(), 12);
+    }
+
+    #[test]
+    fn stops_at_breakpoints() {
+        let mut machine = machine();
+        let cycle = machine.add_breakpoint(Breakpoint::Cycle(5));
+        let above = machine.add_breakpoint(Breakpoint::Register { register: A, ordering: Ordering::Greater, value: 4 });
+
+        assert_eq!(machine.run(()).unwrap(), Stop::Breakpoint(cycle));
+        assert_eq!((machine.cycle(), machine.pc()), (5, 0));
+        assert_eq!(machine.run(()).unwrap(), Stop::Breakpoint(above));
+        assert_eq!((machine.cycle(), machine.registers().get(A)), (6, 5));
+        // Only breaks when the condition starts to hold
+        assert_eq!(machine.run(()).unwrap(), Stop::Halted);
+    }
+
+    #[test]
+    fn traces_each_instruction() {
+        let trace = SharedBuffer::default();
+        let mut machine = machine();
+        machine.trace_to(trace.clone());
+        machine.run(()).unwrap();
+
+        let trace = String::from_utf8(trace.0.borrow().clone()).unwrap();
+        let lines: Vec<_> = trace.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "     2    0 Add(0, 1): a=3 b=3");
+        assert_eq!(lines[8], "    12    2 Jnz(1, -2): a=6 b=0");
+    }
+}